@@ -0,0 +1,95 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::path::Path;
+use utils::generate::{self, Rng};
+use utils::{InputSource, Solution};
+
+const SEED: u64 = 2018;
+
+fn bench_input<S: Solution + Default>(c: &mut Criterion, day: u32, label: &str, input: &str) {
+    let solution = S::default();
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("day{:02}/{}: skipped: {}", day, label, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    group.bench_function(BenchmarkId::new("parse", label), |b| {
+        b.iter(|| S::parse(input))
+    });
+    if solution.part1(&parsed).is_ok() {
+        group.bench_function(BenchmarkId::new("part1", label), |b| {
+            b.iter(|| solution.part1(&parsed))
+        });
+    }
+    if solution.part2(&parsed).is_ok() {
+        group.bench_function(BenchmarkId::new("part2", label), |b| {
+            b.iter(|| solution.part2(&parsed))
+        });
+    }
+    group.finish();
+}
+
+fn bench_day<S: Solution + Default>(
+    c: &mut Criterion,
+    day: u32,
+    generate: Option<fn(&mut Rng) -> String>,
+) {
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+    if let Some(source) = InputSource::lookup(&inputs_dir, day, S::DEFAULT_INPUT) {
+        let label = match source {
+            InputSource::Embedded(_) => "builtin",
+            _ => "input",
+        };
+        match source.read() {
+            Ok(input) => bench_input::<S>(c, day, label, &input),
+            Err(e) => eprintln!("day{:02}/{}: skipped: {}", day, label, e),
+        }
+    }
+    if let Some(generate) = generate {
+        let input = generate(&mut Rng::new(SEED));
+        bench_input::<S>(c, day, "generated", &input);
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, 1, Some(generate::day01));
+    bench_day::<day02::Day02>(c, 2, Some(generate::day02));
+    bench_day::<day03::Day03>(c, 3, Some(generate::day03));
+    bench_day::<day04::Day04>(c, 4, Some(generate::day04));
+    bench_day::<day05::Day05>(c, 5, Some(generate::day05));
+    bench_day::<day06::Day06>(c, 6, Some(generate::day06));
+    bench_day::<day07::Day07>(c, 7, Some(generate::day07));
+    bench_day::<day08::Day08>(c, 8, Some(generate::day08));
+    bench_day::<day09::Day09>(c, 9, None);
+    bench_day::<day10::Day10>(c, 10, Some(generate::day10));
+    bench_day::<day12::Day12>(c, 12, Some(generate::day12));
+    bench_day::<day13::Day13>(c, 13, Some(generate::day13));
+    bench_day::<day14::Day14>(c, 14, None);
+}
+
+// The second part of day 11 searches all the square sizes and takes minutes,
+// so it is measured on growing prefixes of the sizes instead.
+fn day11(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day11");
+    group.bench_function("part1", |b| {
+        b.iter(|| day11::optimize_power(18, 300, 3..=3))
+    });
+    for max_size in [5, 10, 20] {
+        group.bench_with_input(
+            BenchmarkId::new("part2", format!("1..={}", max_size)),
+            &max_size,
+            |b, &max_size| b.iter(|| day11::optimize_power(18, 300, 1..=max_size)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = days, day11
+}
+criterion_main!(benches);
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use utils::Error;
+
+// day -> part -> input hash -> answer
+type Answers = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: Answers,
+}
+
+// The 64-bit FNV-1a hash, which unlike the hashers of the standard library
+// is guaranteed to stay the same between runs and compiler versions.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+fn io_error(path: &Path, e: impl fmt::Display, kind: io::ErrorKind) -> Error {
+    Error::Io(io::Error::new(
+        kind,
+        format!("cannot access {}: {}", path.display(), e),
+    ))
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<AnswerStore, Error> {
+        let answers = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| {
+                Error::invalid(format!("malformed answers file {}: {}", path.display(), e))
+            })?,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Answers::new(),
+            Err(e) => return Err(io_error(path, &e, e.kind())),
+        };
+        Ok(AnswerStore {
+            path: path.to_owned(),
+            answers,
+        })
+    }
+
+    pub fn check(&self, day: u32, part: u32, hash: &str, answer: &str) -> Verdict {
+        let known = self
+            .answers
+            .get(&format!("day{:02}", day))
+            .and_then(|parts| parts.get(&format!("part{}", part)))
+            .and_then(|inputs| inputs.get(hash));
+        match known {
+            None => Verdict::New,
+            Some(known) if known == answer => Verdict::Pass,
+            Some(known) => Verdict::Fail(known.clone()),
+        }
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, hash: &str, answer: &str) {
+        self.answers
+            .entry(format!("day{:02}", day))
+            .or_default()
+            .entry(format!("part{}", part))
+            .or_default()
+            .insert(hash.to_owned(), answer.to_owned());
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let contents = toml::to_string(&self.answers)
+            .map_err(|e| io_error(&self.path, e, io::ErrorKind::InvalidData))?;
+        fs::write(&self.path, contents).map_err(|e| io_error(&self.path, &e, e.kind()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hash_tests() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+        assert_ne!(input_hash("+1\n-2\n"), input_hash("+1\n+2\n"));
+    }
+
+    #[test]
+    fn store_tests() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut store = AnswerStore::load(&path).unwrap();
+        assert!(matches!(store.check(7, 1, "abc", "CABDFE"), Verdict::New));
+        store.record(7, 1, "abc", "CABDFE");
+        store.record(10, 1, "abc", "#..#\n#..#\n");
+        store.save().unwrap();
+
+        let store = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(store.check(7, 1, "abc", "CABDFE"), Verdict::Pass));
+        assert!(matches!(store.check(7, 1, "abd", "CABDFE"), Verdict::New));
+        assert!(matches!(store.check(7, 2, "abc", "15"), Verdict::New));
+        assert!(matches!(
+            store.check(10, 1, "abc", "#..#\n#..#\n"),
+            Verdict::Pass
+        ));
+        match store.check(7, 1, "abc", "CABFDE") {
+            Verdict::Fail(expected) => assert_eq!("CABDFE", expected),
+            _ => panic!("expected a failure"),
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use answers::{input_hash, AnswerStore, Verdict};
+use clap::{Parser, Subcommand, ValueEnum};
+use params::{configured, Configure, Params};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+use utils::{generate, input_path, Error, InputSource};
+
+mod answers;
+mod params;
+
+struct Answer {
+    part: u32,
+    result: Result<(String, Value), Error>,
+    elapsed: Duration,
+}
+
+type Answers = Result<Vec<Answer>, Error>;
+
+type Timings = [Duration; 3];
+
+struct Day {
+    default_input: Option<&'static str>,
+    hash: fn(&str, &Params) -> String,
+    solve: fn(&str, Option<u32>, &Params) -> Answers,
+    time: fn(&str, u32, &Params) -> Result<Timings, Error>,
+}
+
+// The parameters of the day are hashed together with the input, because the
+// answers depend on them as well.
+fn hash<S: Configure>(input: &str, params: &Params) -> String {
+    input_hash(&format!("{:?}\n{}", configured::<S>(params), input))
+}
+
+fn solve<S: Configure>(input: &str, part: Option<u32>, params: &Params) -> Answers {
+    let solution = configured::<S>(params);
+    let input = S::parse(input)?;
+    let mut answers = Vec::new();
+    if part != Some(2) {
+        answers.push(answer(1, || solution.part1(&input)));
+    }
+    if part != Some(1) {
+        answers.push(answer(2, || solution.part2(&input)));
+    }
+    Ok(answers)
+}
+
+fn answer<A: Display + Serialize>(part: u32, solve: impl FnOnce() -> Result<A, Error>) -> Answer {
+    let start = Instant::now();
+    let result = solve();
+    let elapsed = start.elapsed();
+    Answer {
+        part,
+        result: result.map(|answer| {
+            let text = answer.to_string();
+            let value = serde_json::to_value(&answer).unwrap_or_else(|_| json!(text));
+            (text, value)
+        }),
+        elapsed,
+    }
+}
+
+fn time_runs<T>(runs: u32, mut f: impl FnMut() -> Result<T, Error>) -> Result<Duration, Error> {
+    let start = Instant::now();
+    for _ in 0..runs {
+        f()?;
+    }
+    Ok(start.elapsed() / runs)
+}
+
+fn time<S: Configure>(input: &str, runs: u32, params: &Params) -> Result<Timings, Error> {
+    let solution = configured::<S>(params);
+    let parse = time_runs(runs, || S::parse(input))?;
+    let input = S::parse(input)?;
+    let part1 = time_runs(runs, || solution.part1(&input))?;
+    let part2 = time_runs(runs, || solution.part2(&input))?;
+    Ok([parse, part1, part2])
+}
+
+const fn day<S: Configure>() -> Day {
+    Day {
+        default_input: S::DEFAULT_INPUT,
+        hash: hash::<S>,
+        solve: solve::<S>,
+        time: time::<S>,
+    }
+}
+
+const DAYS: [Day; 14] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+];
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2018 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per line and part
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a single day or of all the days
+    Run {
+        /// The day to run
+        #[arg(
+            required_unless_present = "all",
+            value_parser = clap::value_parser!(u32).range(1..=DAYS.len() as i64)
+        )]
+        day: Option<u32>,
+        /// Runs all the days, reading their inputs from the inputs directory
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Runs only the given part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// File with the puzzle input, or "-" for stdin [default: the one in the
+        /// inputs directory, the built-in one or stdin]
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Directory with the puzzle inputs named dayNN.txt
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Checks the answers against the ones in the answers file
+        #[arg(long)]
+        check: bool,
+        /// Checks the answers and adds the new ones to the answers file
+        #[arg(long)]
+        record: bool,
+        /// File with the known answers for every day, part and input
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        #[command(flatten)]
+        params: Params,
+    },
+    /// Times the parsing and both parts of the days and prints a summary table
+    Bench {
+        /// The days to time (all of them if missing)
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=DAYS.len() as i64))]
+        days: Vec<u32>,
+        /// How many times to run each step, reporting the mean
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Directory with the puzzle inputs named dayNN.txt
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        #[command(flatten)]
+        params: Params,
+    },
+    /// Prints a random puzzle input for a day, for testing the solutions
+    Generate {
+        /// The day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=DAYS.len() as i64))]
+        day: u32,
+        /// The seed of the random generator, the same seed giving the same input
+        #[arg(long, default_value_t = 2018)]
+        seed: u64,
+    },
+}
+
+fn elapsed_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn print_labelled(label: &str, text: &str) {
+    if text.contains('\n') {
+        println!("{}:\n{}", label, text.trim_end());
+    } else {
+        println!("{}: {}", label, text);
+    }
+}
+
+fn report(day: u32, answer: &Answer, verdict: Option<&Verdict>, format: Format) {
+    let part = answer.part;
+    match (format, &answer.result) {
+        (Format::Text, Ok((text, _))) => {
+            let label = match verdict {
+                Some(verdict) => format!("Day {}, part {} [{}]", day, part, verdict),
+                None => format!("Day {}, part {}", day, part),
+            };
+            print_labelled(&label, text);
+            if let Some(Verdict::Fail(expected)) = verdict {
+                print_labelled("Expected", expected);
+            }
+        }
+        (Format::Text, Err(e)) => eprintln!("Day {}, part {}: error: {}", day, part, e),
+        (Format::Json, Ok((_, value))) => {
+            let mut record = json!({
+                "day": day,
+                "part": part,
+                "answer": value,
+                "elapsed_ms": elapsed_ms(answer.elapsed),
+            });
+            if let Some(verdict) = verdict {
+                record["status"] = json!(verdict.to_string());
+                if let Verdict::Fail(expected) = verdict {
+                    record["expected"] = json!(expected);
+                }
+            }
+            println!("{}", record);
+        }
+        (Format::Json, Err(e)) => println!(
+            "{}",
+            json!({
+                "day": day,
+                "part": part,
+                "error": e.to_string(),
+                "elapsed_ms": elapsed_ms(answer.elapsed),
+            })
+        ),
+    }
+}
+
+fn report_day_error(day: u32, error: &Error, format: Format) {
+    match format {
+        Format::Text => eprintln!("Day {}: error: {}", day, error),
+        Format::Json => println!("{}", json!({ "day": day, "error": error.to_string() })),
+    }
+}
+
+struct Verifier {
+    store: AnswerStore,
+    record: bool,
+}
+
+impl Verifier {
+    // Known answers are never overwritten, so that a regression doesn't
+    // replace the right answer.
+    fn verify(&mut self, day: u32, part: u32, hash: &str, answer: &str) -> Verdict {
+        let verdict = self.store.check(day, part, hash, answer);
+        if self.record {
+            if let Verdict::New = verdict {
+                self.store.record(day, part, hash, answer);
+            }
+        }
+        verdict
+    }
+}
+
+fn run_day(
+    day: u32,
+    part: Option<u32>,
+    input: &str,
+    format: Format,
+    params: &Params,
+    verifier: &mut Option<Verifier>,
+) -> bool {
+    let answers = match (DAYS[day as usize - 1].solve)(input, part, params) {
+        Ok(answers) => answers,
+        Err(e) => {
+            report_day_error(day, &e, format);
+            return false;
+        }
+    };
+    let hash = (DAYS[day as usize - 1].hash)(input, params);
+    let mut success = true;
+    for answer in &answers {
+        let verdict = match (verifier.as_mut(), &answer.result) {
+            (Some(verifier), Ok((text, _))) => Some(verifier.verify(day, answer.part, &hash, text)),
+            _ => None,
+        };
+        success &= answer.result.is_ok() && !matches!(verdict, Some(Verdict::Fail(_)));
+        report(day, answer, verdict.as_ref(), format);
+    }
+    success
+}
+
+// The days without a built-in input must have one in the inputs directory.
+fn day_input(day: u32, inputs_dir: &Path) -> Result<String, Error> {
+    InputSource::lookup(inputs_dir, day, DAYS[day as usize - 1].default_input)
+        .unwrap_or_else(|| InputSource::File(input_path(inputs_dir, day)))
+        .read()
+}
+
+fn run(
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<PathBuf>,
+    inputs_dir: PathBuf,
+    format: Format,
+    params: &Params,
+    verifier: &mut Option<Verifier>,
+) -> Result<bool, Error> {
+    match day {
+        Some(day) => {
+            let default_input = DAYS[day as usize - 1].default_input;
+            let input =
+                InputSource::choose(input.as_deref(), &inputs_dir, day, default_input).read()?;
+            Ok(run_day(day, part, &input, format, params, verifier))
+        }
+        None => {
+            let mut success = true;
+            for day in 1..=DAYS.len() as u32 {
+                match day_input(day, &inputs_dir) {
+                    Ok(input) => success &= run_day(day, part, &input, format, params, verifier),
+                    Err(e) => {
+                        report_day_error(day, &e, format);
+                        success = false;
+                    }
+                }
+            }
+            Ok(success)
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", elapsed_ms(duration))
+}
+
+fn bench(mut days: Vec<u32>, runs: u32, inputs_dir: PathBuf, params: &Params) -> bool {
+    if days.is_empty() {
+        days = (1..=DAYS.len() as u32).collect();
+    }
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    let mut success = true;
+    let mut total = Duration::ZERO;
+    for day in days {
+        let timings = day_input(day, &inputs_dir)
+            .and_then(|input| (DAYS[day as usize - 1].time)(&input, runs, params));
+        match timings {
+            Ok(timings) => {
+                let day_total = timings.iter().sum();
+                total += day_total;
+                println!(
+                    "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+                    day,
+                    format_duration(timings[0]),
+                    format_duration(timings[1]),
+                    format_duration(timings[2]),
+                    format_duration(day_total)
+                );
+            }
+            Err(e) => {
+                println!("{:>3}  error: {}", day, e);
+                success = false;
+            }
+        }
+    }
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "",
+        "",
+        "",
+        "",
+        format_duration(total)
+    );
+    success
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            day,
+            all: _,
+            part,
+            input,
+            inputs_dir,
+            format,
+            check,
+            record,
+            answers,
+            params,
+        } => {
+            let mut verifier = if check || record {
+                match AnswerStore::load(&answers) {
+                    Ok(store) => Some(Verifier { store, record }),
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        process::exit(1);
+                    }
+                }
+            } else {
+                None
+            };
+            let result = run(day, part, input, inputs_dir, format, &params, &mut verifier);
+            match verifier {
+                Some(Verifier {
+                    store,
+                    record: true,
+                }) => store.save().and(result),
+                _ => result,
+            }
+        }
+        Command::Bench {
+            days,
+            runs,
+            inputs_dir,
+            params,
+        } => Ok(bench(days, runs, inputs_dir, &params)),
+        Command::Generate { day, seed } => match generate::generate(day, seed) {
+            Some(input) => {
+                print!("{}", input);
+                Ok(true)
+            }
+            None => Err(Error::invalid(format!(
+                "there is no generator for day {}",
+                day
+            ))),
+        },
+    };
+    match result {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
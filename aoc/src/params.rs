@@ -0,0 +1,91 @@
+use clap::Args;
+use std::fmt::Debug;
+use utils::Solution;
+
+#[derive(Args)]
+#[command(next_help_heading = "Puzzle parameters")]
+pub struct Params {
+    /// Day 6: the total distance to all the locations must be below this
+    #[arg(long)]
+    max_distance: Option<u32>,
+    /// Day 7: the number of workers assembling the sleigh
+    #[arg(long)]
+    workers: Option<usize>,
+    /// Day 7: the seconds every step takes on top of its letter's position
+    #[arg(long)]
+    step_duration: Option<u32>,
+    /// Day 9: the number of players
+    #[arg(long)]
+    players: Option<usize>,
+    /// Day 9: the points of the last marble
+    #[arg(long)]
+    last_marble: Option<u32>,
+    /// Day 11: the serial number of the grid
+    #[arg(long)]
+    serial_number: Option<i32>,
+    /// Day 11: the width and height of the grid
+    #[arg(long)]
+    grid_size: Option<usize>,
+    /// Day 14: the number of recipes
+    #[arg(long)]
+    recipes: Option<usize>,
+}
+
+fn set<T: Copy>(field: &mut T, param: Option<T>) {
+    if let Some(value) = param {
+        *field = value;
+    }
+}
+
+pub trait Configure: Solution + Default + Debug {
+    fn configure(&mut self, _params: &Params) {}
+}
+
+pub fn configured<S: Configure>(params: &Params) -> S {
+    let mut solution = S::default();
+    solution.configure(params);
+    solution
+}
+
+impl Configure for day01::Day01 {}
+impl Configure for day02::Day02 {}
+impl Configure for day03::Day03 {}
+impl Configure for day04::Day04 {}
+impl Configure for day05::Day05 {}
+impl Configure for day08::Day08 {}
+impl Configure for day10::Day10 {}
+impl Configure for day12::Day12 {}
+impl Configure for day13::Day13 {}
+
+impl Configure for day06::Day06 {
+    fn configure(&mut self, params: &Params) {
+        set(&mut self.max_total_distance, params.max_distance);
+    }
+}
+
+impl Configure for day07::Day07 {
+    fn configure(&mut self, params: &Params) {
+        set(&mut self.n_workers, params.workers);
+        set(&mut self.base_duration, params.step_duration);
+    }
+}
+
+impl Configure for day09::Day09 {
+    fn configure(&mut self, params: &Params) {
+        self.num_players = params.players.or(self.num_players);
+        self.top_marble = params.last_marble.or(self.top_marble);
+    }
+}
+
+impl Configure for day11::Day11 {
+    fn configure(&mut self, params: &Params) {
+        self.serial_number = params.serial_number.or(self.serial_number);
+        set(&mut self.grid_size, params.grid_size);
+    }
+}
+
+impl Configure for day14::Day14 {
+    fn configure(&mut self, params: &Params) {
+        self.puzzle_input = params.recipes.or(self.puzzle_input);
+    }
+}
@@ -0,0 +1,833 @@
+#[cfg(feature = "bigint")]
+extern crate num_bigint;
+#[cfg(test)]
+extern crate proptest;
+extern crate serde;
+extern crate utils;
+
+#[cfg(feature = "bigint")]
+pub use num_bigint::BigInt;
+use serde::Serialize;
+use std::any;
+use std::collections::HashMap;
+#[cfg(feature = "bigint")]
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use utils::{Error, Solution};
+
+/// The integers the frequencies can be computed in, with the arithmetic
+/// returning `None` on overflow.
+pub trait Frequency: Clone + Ord + Hash + Debug + Display + FromStr + Serialize {
+    fn zero() -> Self;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+
+    fn checked_rem_euclid(&self, other: &Self) -> Option<Self>;
+
+    fn to_u64(&self) -> Option<u64>;
+}
+
+macro_rules! primitive_frequency {
+    ($($int:ty),*) => {$(
+        impl Frequency for $int {
+            fn zero() -> $int {
+                0
+            }
+
+            fn checked_add(&self, other: &$int) -> Option<$int> {
+                <$int>::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &$int) -> Option<$int> {
+                <$int>::checked_sub(*self, *other)
+            }
+
+            fn checked_div(&self, other: &$int) -> Option<$int> {
+                <$int>::checked_div(*self, *other)
+            }
+
+            fn checked_rem_euclid(&self, other: &$int) -> Option<$int> {
+                <$int>::checked_rem_euclid(*self, *other)
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                if *self < 0 || *self as u128 > u64::MAX as u128 {
+                    None
+                } else {
+                    Some(*self as u64)
+                }
+            }
+        }
+    )*};
+}
+
+primitive_frequency!(i32, i64, i128);
+
+#[cfg(feature = "bigint")]
+impl Frequency for BigInt {
+    fn zero() -> BigInt {
+        BigInt::from(0)
+    }
+
+    fn checked_add(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self - other)
+    }
+
+    fn checked_div(&self, other: &BigInt) -> Option<BigInt> {
+        if *other == BigInt::zero() {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+
+    fn checked_rem_euclid(&self, other: &BigInt) -> Option<BigInt> {
+        if *other == BigInt::zero() {
+            return None;
+        }
+        let remainder = self % other;
+        if remainder < BigInt::zero() {
+            Some(
+                remainder
+                    + if *other < BigInt::zero() {
+                        -other
+                    } else {
+                        other.clone()
+                    },
+            )
+        } else {
+            Some(remainder)
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+}
+
+fn overflow<T>() -> Error {
+    Error::invalid(format!(
+        "the frequency does not fit in {}",
+        any::type_name::<T>()
+    ))
+}
+
+pub fn parse_input<T: Frequency>(input: &str) -> Result<Vec<T>, Error>
+where
+    T::Err: Display,
+{
+    let mut freq_changes = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        freq_changes.push(parse_change(line, i + 1)?);
+    }
+
+    Ok(freq_changes)
+}
+
+fn parse_change<T: Frequency>(line: &str, line_number: usize) -> Result<T, Error>
+where
+    T::Err: Display,
+{
+    line.parse().map_err(|e| {
+        Error::parse(
+            line_number,
+            1,
+            format!("illegal frequency change '{}': {}", line, e),
+        )
+    })
+}
+
+pub fn final_frequency<T: Frequency>(freq_changes: &[T]) -> Result<T, Error> {
+    freq_changes
+        .iter()
+        .try_fold(T::zero(), |frequency, delta| frequency.checked_add(delta))
+        .ok_or_else(overflow::<T>)
+}
+
+/// The first frequency reached twice, and when.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repeat<T> {
+    pub frequency: T,
+    /// The full passes over the changes before the one reaching it again.
+    pub passes: u64,
+    /// The change reaching it first, or `None` for the starting frequency.
+    pub first_index: Option<usize>,
+    /// The change reaching it again.
+    pub repeat_index: usize,
+    /// The changes made until it is reached again.
+    pub steps: u64,
+}
+
+impl<T: Display> fmt::Display for Repeat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.first_index {
+            Some(index) => write!(
+                f,
+                "{} is reached by the change at index {}",
+                self.frequency, index
+            )?,
+            None => write!(f, "{} is the starting frequency", self.frequency)?,
+        }
+        let passes = if self.passes == 1 { "pass" } else { "passes" };
+        write!(
+            f,
+            " and again by the change at index {} after {} full {}, {} changes in total",
+            self.repeat_index, self.passes, passes, self.steps
+        )
+    }
+}
+
+// The frequencies before every change of the first pass, and the drift of a
+// whole pass.
+fn partial_deltas<T: Frequency>(freq_changes: &[T]) -> Result<(Vec<T>, T), Error> {
+    let mut big_delta = T::zero();
+    let mut partial_deltas = Vec::with_capacity(freq_changes.len());
+
+    for delta in freq_changes {
+        let next = big_delta.checked_add(delta).ok_or_else(overflow::<T>)?;
+        partial_deltas.push(big_delta);
+        big_delta = next;
+    }
+
+    Ok((partial_deltas, big_delta))
+}
+
+// The repeat of the frequency reached after `j` changes, which is reached again
+// after `iterations` full passes and another `i` changes.
+fn repeat<T: Frequency>(
+    partial_deltas: &[T],
+    (iterations, i, j): (T, usize, usize),
+) -> Result<Repeat<T>, Error> {
+    let n = partial_deltas.len() as u64;
+    let steps = iterations
+        .to_u64()
+        .and_then(|iterations| iterations.checked_mul(n))
+        .and_then(|steps| steps.checked_add(i as u64))
+        .ok_or_else(|| Error::invalid("the frequency repeats after too many changes to count"))?;
+    Ok(Repeat {
+        frequency: partial_deltas[j].clone(),
+        passes: (steps - 1) / n,
+        first_index: j.checked_sub(1),
+        repeat_index: ((steps - 1) % n) as usize,
+        steps,
+    })
+}
+
+/// Compares every pair of frequencies of the first pass, in O(n²).
+pub fn find_repeat<T: Frequency>(freq_changes: &[T]) -> Result<Option<Repeat<T>>, Error> {
+    let n = freq_changes.len();
+    let (partial_deltas, big_delta) = partial_deltas(freq_changes)?;
+    let zero = T::zero();
+
+    let mut best_candidate: Option<(T, usize, usize)> = None;
+
+    // The frequency reached after `j` changes is reached again after `iterations`
+    // full passes and another `i` changes.
+    for i in 0..n {
+        for j in 0..n {
+            let delta = partial_deltas[j]
+                .checked_sub(&partial_deltas[i])
+                .ok_or_else(overflow::<T>)?;
+            let iterations = if delta == zero {
+                if j >= i {
+                    continue;
+                }
+                zero.clone()
+            } else if big_delta != zero
+                && delta.checked_rem_euclid(&big_delta) == Some(zero.clone())
+            {
+                match delta.checked_div(&big_delta) {
+                    Some(quotient) if quotient > zero => quotient,
+                    _ => continue,
+                }
+            } else {
+                continue;
+            };
+            let new_candidate = (iterations, i, j);
+            best_candidate = match best_candidate {
+                None => Some(new_candidate),
+                Some(best_candidate) => Some(best_candidate.min(new_candidate)),
+            };
+        }
+    }
+
+    // Without any drift, the starting frequency comes back after the first pass,
+    // as if after `n` changes of the first pass.
+    if big_delta == zero && n > 0 {
+        let one_pass = (T::zero(), n, 0);
+        best_candidate = Some(best_candidate.map_or(one_pass.clone(), |c| c.min(one_pass)));
+    }
+
+    best_candidate
+        .map(|candidate| repeat(&partial_deltas, candidate))
+        .transpose()
+}
+
+pub fn repeating_frequency<T: Frequency>(freq_changes: &[T]) -> Result<Option<T>, Error> {
+    Ok(find_repeat(freq_changes)?.map(|repeat| repeat.frequency))
+}
+
+/// Finds the same repeat as `find_repeat` in O(n log n). Every pass moves the
+/// frequencies by the drift, so a frequency can only come back as one that
+/// leaves the same remainder when divided by the drift, and the first to come
+/// back is the closest one in the direction of the drift.
+pub fn find_repeat_by_residue<T: Frequency>(
+    freq_changes: &[T],
+) -> Result<Option<Repeat<T>>, Error> {
+    let (partial_deltas, big_delta) = partial_deltas(freq_changes)?;
+    repeat_by_residue(&partial_deltas, big_delta)
+}
+
+fn repeat_by_residue<T: Frequency>(
+    partial_deltas: &[T],
+    big_delta: T,
+) -> Result<Option<Repeat<T>>, Error> {
+    let n = partial_deltas.len();
+    let zero = T::zero();
+
+    // A frequency reached twice in the first pass comes back before any other.
+    let mut first_seen = HashMap::with_capacity(n);
+    for (i, frequency) in partial_deltas.iter().enumerate() {
+        if let Some(&j) = first_seen.get(frequency) {
+            return repeat(partial_deltas, (zero, i, j)).map(Some);
+        }
+        first_seen.insert(frequency, i);
+    }
+    if n == 0 {
+        return Ok(None);
+    }
+    // Without any drift, the starting frequency comes back after the first pass,
+    // as if after `n` changes of the first pass.
+    if big_delta == zero {
+        return repeat(partial_deltas, (zero, n, 0)).map(Some);
+    }
+
+    let mut classes: HashMap<T, Vec<(&T, usize)>> = HashMap::new();
+    for (i, frequency) in partial_deltas.iter().enumerate() {
+        let residue = frequency
+            .checked_rem_euclid(&big_delta)
+            .ok_or_else(overflow::<T>)?;
+        classes.entry(residue).or_default().push((frequency, i));
+    }
+
+    let mut best_candidate: Option<(T, usize, usize)> = None;
+    for class in classes.values_mut() {
+        class.sort_unstable();
+        if big_delta < zero {
+            class.reverse();
+        }
+        for pair in class.windows(2) {
+            let ((from, i), (to, j)) = (pair[0], pair[1]);
+            let iterations = to
+                .checked_sub(from)
+                .and_then(|delta| delta.checked_div(&big_delta))
+                .ok_or_else(overflow::<T>)?;
+            let candidate = (iterations, i, j);
+            best_candidate = Some(match best_candidate {
+                None => candidate,
+                Some(best_candidate) => best_candidate.min(candidate),
+            });
+        }
+    }
+
+    best_candidate
+        .map(|candidate| repeat(partial_deltas, candidate))
+        .transpose()
+}
+
+pub fn repeating_frequency_by_residue<T: Frequency>(
+    freq_changes: &[T],
+) -> Result<Option<T>, Error> {
+    Ok(find_repeat_by_residue(freq_changes)?.map(|repeat| repeat.frequency))
+}
+
+/// A frequency reached twice in a stream of changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamRepeat<T> {
+    pub frequency: T,
+    /// The change reaching it first, or `None` for the starting frequency.
+    pub first_index: Option<u64>,
+    /// The change reaching it again.
+    pub repeat_index: u64,
+}
+
+impl<T: Display> fmt::Display for StreamRepeat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.first_index {
+            Some(index) => write!(
+                f,
+                "{} is reached by the change at index {}",
+                self.frequency, index
+            )?,
+            None => write!(f, "{} is the starting frequency", self.frequency)?,
+        }
+        write!(f, " and again by the change at index {}", self.repeat_index)
+    }
+}
+
+/// Follows the frequency over changes fed one at a time, e.g. from a stream
+/// too long to keep in memory. Only the frequencies reached until the first
+/// repeat are remembered, and they are forgotten once it is found.
+#[derive(Clone, Debug)]
+pub struct FrequencyTracker<T = i64> {
+    frequency: T,
+    changes: u64,
+    first_indices: HashMap<T, Option<u64>>,
+    repeat: Option<StreamRepeat<T>>,
+}
+
+impl<T: Frequency> Default for FrequencyTracker<T> {
+    fn default() -> FrequencyTracker<T> {
+        FrequencyTracker::new()
+    }
+}
+
+impl<T: Frequency> FrequencyTracker<T> {
+    pub fn new() -> FrequencyTracker<T> {
+        let mut first_indices = HashMap::new();
+        first_indices.insert(T::zero(), None);
+        FrequencyTracker {
+            frequency: T::zero(),
+            changes: 0,
+            first_indices,
+            repeat: None,
+        }
+    }
+
+    /// The frequency after all the changes so far.
+    pub fn frequency(&self) -> &T {
+        &self.frequency
+    }
+
+    /// The number of changes so far.
+    pub fn changes(&self) -> u64 {
+        self.changes
+    }
+
+    /// The first frequency reached twice so far.
+    pub fn repeat(&self) -> Option<&StreamRepeat<T>> {
+        self.repeat.as_ref()
+    }
+
+    /// Applies the change, returning the first repeat if this change made it.
+    pub fn push(&mut self, change: &T) -> Result<Option<&StreamRepeat<T>>, Error> {
+        self.frequency = self
+            .frequency
+            .checked_add(change)
+            .ok_or_else(overflow::<T>)?;
+        let index = self.changes;
+        self.changes += 1;
+
+        if self.repeat.is_some() {
+            return Ok(None);
+        }
+        if let Some(&first_index) = self.first_indices.get(&self.frequency) {
+            self.first_indices = HashMap::new();
+            self.repeat = Some(StreamRepeat {
+                frequency: self.frequency.clone(),
+                first_index,
+                repeat_index: index,
+            });
+            return Ok(self.repeat.as_ref());
+        }
+        self.first_indices
+            .insert(self.frequency.clone(), Some(index));
+        Ok(None)
+    }
+
+    /// Parses a line of the puzzle input and applies the change it holds.
+    pub fn push_line(&mut self, line: &str) -> Result<Option<&StreamRepeat<T>>, Error>
+    where
+        T::Err: Display,
+    {
+        let line_number = self.changes as usize + 1;
+        self.push(&parse_change(line.trim(), line_number)?)
+    }
+
+    /// Applies the changes until the first repeat, leaving the rest of them
+    /// in the iterator, so that it can be an endless one.
+    pub fn until_repeat<'a, I>(&mut self, changes: I) -> Result<Option<&StreamRepeat<T>>, Error>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        if self.repeat.is_none() {
+            for change in changes {
+                if self.push(change)?.is_some() {
+                    break;
+                }
+            }
+        }
+        Ok(self.repeat.as_ref())
+    }
+
+    /// Reads the changes, one per line, to the end of `reader`.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<FrequencyTracker<T>, Error>
+    where
+        T::Err: Display,
+    {
+        let mut tracker = FrequencyTracker::new();
+        tracker.read_lines(reader, false)?;
+        Ok(tracker)
+    }
+
+    /// Reads the changes, one per line, until the first repeat, so that
+    /// `reader` can be a pipe that is never closed.
+    pub fn read_until_repeat<R: BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<Option<&StreamRepeat<T>>, Error>
+    where
+        T::Err: Display,
+    {
+        self.read_lines(reader, true)?;
+        Ok(self.repeat.as_ref())
+    }
+
+    // Blank lines are skipped, but still counted in the line numbers of the
+    // errors.
+    fn read_lines<R: BufRead>(&mut self, reader: R, until_repeat: bool) -> Result<(), Error>
+    where
+        T::Err: Display,
+    {
+        if until_repeat && self.repeat.is_some() {
+            return Ok(());
+        }
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if self.push(&parse_change(line, i + 1)?)?.is_some() && until_repeat {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The first repeat if the changes so far were applied over and over, like
+    /// in the second part. Until a frequency repeats, the frequencies reached
+    /// are all remembered, which is all the residue search needs.
+    pub fn cycled_repeat(&self) -> Result<Option<Repeat<T>>, Error> {
+        if let Some(ref repeat) = self.repeat {
+            // The first pass is the same as the stream.
+            return Ok(Some(Repeat {
+                frequency: repeat.frequency.clone(),
+                passes: 0,
+                first_index: repeat.first_index.map(|index| index as usize),
+                repeat_index: repeat.repeat_index as usize,
+                steps: repeat.repeat_index + 1,
+            }));
+        }
+
+        let n = self.changes as usize;
+        let mut partial_deltas = vec![T::zero(); n + 1];
+        for (frequency, index) in &self.first_indices {
+            let position = index.map_or(0, |index| index as usize + 1);
+            partial_deltas[position] = frequency.clone();
+        }
+        partial_deltas.pop();
+        repeat_by_residue(&partial_deltas, self.frequency.clone())
+    }
+}
+
+/// Solves the puzzle with frequencies of type `T`.
+pub struct Day01<T = i64>(PhantomData<T>);
+
+impl<T> Default for Day01<T> {
+    fn default() -> Day01<T> {
+        Day01(PhantomData)
+    }
+}
+
+// The answers are stored under a hash of the solution's debug output, which
+// is the same whatever the type.
+impl<T> Debug for Day01<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day01")
+    }
+}
+
+impl<T: Frequency> Solution for Day01<T>
+where
+    T::Err: Display,
+{
+    type Input = Vec<T>;
+    type Answer1 = T;
+    type Answer2 = T;
+
+    fn parse(input: &str) -> Result<Vec<T>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, freq_changes: &Vec<T>) -> Result<T, Error> {
+        final_frequency(freq_changes)
+    }
+
+    fn part2(&self, freq_changes: &Vec<T>) -> Result<T, Error> {
+        Ok(self.repeat(freq_changes)?.frequency)
+    }
+}
+
+impl<T: Frequency> Day01<T> {
+    pub fn repeat(&self, freq_changes: &[T]) -> Result<Repeat<T>, Error> {
+        find_repeat_by_residue(freq_changes)?
+            .ok_or_else(|| Error::no_solution("the frequencies will never repeat"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::io::{Cursor, Read};
+
+    // Walks through the changes until a frequency repeats, giving up after so
+    // many passes that the frequencies of the small inputs below would have
+    // repeated.
+    fn naive_find_repeat(freq_changes: &[i32]) -> Option<Repeat<i32>> {
+        let mut first_indices = HashMap::new();
+        let mut frequency = 0;
+        let mut steps = 0;
+        first_indices.insert(frequency, None);
+        for passes in 0..1_000 {
+            for (index, change) in freq_changes.iter().enumerate() {
+                frequency += change;
+                steps += 1;
+                if let Some(&first_index) = first_indices.get(&frequency) {
+                    return Some(Repeat {
+                        frequency,
+                        passes,
+                        first_index,
+                        repeat_index: index,
+                        steps,
+                    });
+                }
+                first_indices.insert(frequency, Some(index));
+            }
+        }
+        None
+    }
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let day01 = Day01::<i64>::default();
+        let freq_changes = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(3, day01.part1(&freq_changes).unwrap());
+        assert_eq!(3, final_frequency(&[1, 1, 1]).unwrap());
+        assert_eq!(0, final_frequency(&[1, 1, -2]).unwrap());
+        assert_eq!(-6, final_frequency(&[-1, -2, -3]).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let day01 = Day01::<i64>::default();
+        let freq_changes = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(2, day01.part2(&freq_changes).unwrap());
+        let examples: [(&[i32], i32); 5] = [
+            (&[1, -1], 0),
+            (&[3, 3, 4, -2, -4], 10),
+            (&[-6, 3, 8, 5, -6], 5),
+            (&[7, 7, -2, -7, -4], 14),
+            (&[5, -2, 2, -5, 3], 5),
+        ];
+        for &(freq_changes, frequency) in &examples {
+            assert_eq!(Some(frequency), repeating_frequency(freq_changes).unwrap());
+            assert_eq!(
+                Some(frequency),
+                repeating_frequency_by_residue(freq_changes).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn find_repeat_tests() {
+        let freq_changes = Day01::<i64>::parse(EXAMPLE).unwrap();
+        let repeat = Repeat {
+            frequency: 2,
+            passes: 1,
+            first_index: Some(2),
+            repeat_index: 1,
+            steps: 6,
+        };
+        assert_eq!(Some(repeat.clone()), find_repeat(&freq_changes).unwrap());
+        assert_eq!(
+            "2 is reached by the change at index 2 and again by the change at index 1 \
+             after 1 full pass, 6 changes in total",
+            repeat.to_string()
+        );
+        let start = find_repeat(&[1, -1]).unwrap().unwrap();
+        assert_eq!(
+            (None, 1, 0, 2),
+            (
+                start.first_index,
+                start.repeat_index,
+                start.passes,
+                start.steps
+            )
+        );
+        assert_eq!(None, find_repeat::<i32>(&[]).unwrap());
+        assert_eq!(None, find_repeat(&[1, 2]).unwrap());
+    }
+
+    #[test]
+    fn overflow_tests() {
+        let input = "+9223372036854775807\n+1\n-9223372036854775807\n";
+        let narrow = Day01::<i64>::parse(input).unwrap();
+        assert_eq!(
+            "invalid input: the frequency does not fit in i64",
+            final_frequency(&narrow).unwrap_err().to_string()
+        );
+        assert!(find_repeat(&narrow).is_err());
+        assert!(find_repeat_by_residue(&narrow).is_err());
+
+        let wide = Day01::<i128>::parse(input).unwrap();
+        assert_eq!(1, final_frequency(&wide).unwrap());
+        let repeat = find_repeat_by_residue(&wide).unwrap().unwrap();
+        assert_eq!((i64::MAX as i128 + 1, 4), (repeat.frequency, repeat.steps));
+        assert_eq!(Some(repeat), find_repeat(&wide).unwrap());
+
+        // The frequency comes back after more changes than fit in a u64.
+        let slow = [i128::MAX / 2, 1 - i128::MAX / 2];
+        assert_eq!(
+            "invalid input: the frequency repeats after too many changes to count",
+            find_repeat_by_residue(&slow).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn frequency_tracker_tests() {
+        let mut tracker = FrequencyTracker::<i64>::new();
+        assert_eq!(None, tracker.push_line("+3").unwrap());
+        assert_eq!(None, tracker.push_line("+3").unwrap());
+        assert_eq!(None, tracker.push_line("+4").unwrap());
+        assert_eq!(None, tracker.push_line("-2").unwrap());
+        assert_eq!((&8, 4), (tracker.frequency(), tracker.changes()));
+        let repeat = tracker.push_line("-2").unwrap().cloned().unwrap();
+        assert_eq!(
+            "6 is reached by the change at index 1 and again by the change at index 4",
+            repeat.to_string()
+        );
+        assert_eq!(None, tracker.push(&-4).unwrap());
+        assert_eq!((&2, Some(&repeat)), (tracker.frequency(), tracker.repeat()));
+        assert_eq!(
+            "line 7, column 1: illegal frequency change 'x': invalid digit found in string",
+            tracker.push_line("x").unwrap_err().to_string()
+        );
+
+        // The changes of the example, repeated without end.
+        let freq_changes = Day01::<i64>::parse(EXAMPLE).unwrap();
+        let mut changes = freq_changes.iter().cycle();
+        let mut tracker = FrequencyTracker::new();
+        let repeat = tracker.until_repeat(&mut changes).unwrap().cloned();
+        assert_eq!(
+            Some(StreamRepeat {
+                frequency: 2,
+                first_index: Some(2),
+                repeat_index: 5,
+            }),
+            repeat
+        );
+        assert_eq!(Some(&3), changes.next());
+
+        let mut tracker = FrequencyTracker::new();
+        tracker.push(&i64::MAX).unwrap();
+        assert!(tracker.push(&1).is_err());
+    }
+
+    #[test]
+    fn from_reader_tests() {
+        let tracker = FrequencyTracker::<i64>::from_reader(Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(
+            (&3, 4, None),
+            (tracker.frequency(), tracker.changes(), tracker.repeat())
+        );
+        let freq_changes = Day01::<i64>::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            find_repeat_by_residue(&freq_changes).unwrap(),
+            tracker.cycled_repeat().unwrap()
+        );
+
+        let input = "+3\r\n\n+3\n+4\n-2\n-2\n+10\n";
+        let tracker = FrequencyTracker::<i64>::from_reader(Cursor::new(input)).unwrap();
+        assert_eq!(&16, tracker.frequency());
+        let repeat = tracker.cycled_repeat().unwrap().unwrap();
+        assert_eq!(
+            "6 is reached by the change at index 1 and again by the change at index 4 \
+             after 0 full passes, 5 changes in total",
+            repeat.to_string()
+        );
+
+        let mut reader = Cursor::new(input);
+        let mut tracker = FrequencyTracker::<i64>::new();
+        let repeat = tracker.read_until_repeat(&mut reader).unwrap().cloned();
+        assert_eq!(Some(6), repeat.map(|repeat| repeat.frequency));
+        assert_eq!(&6, tracker.frequency());
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!("+10\n", rest);
+
+        let error = FrequencyTracker::<i64>::from_reader(Cursor::new("+1\n\n+x\n"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            "line 3, column 1: illegal frequency change '+x': invalid digit found in string",
+            error.to_string()
+        );
+        let error = FrequencyTracker::<i64>::from_reader(Cursor::new(b"+1\n\xff\n" as &[u8]))
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("UTF-8"));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint_tests() {
+        let input = "+9223372036854775807\n+9223372036854775807\n-1\n+1\n";
+        let freq_changes = Day01::<BigInt>::parse(input).unwrap();
+        let frequency = final_frequency(&freq_changes).unwrap();
+        assert_eq!("18446744073709551614", frequency.to_string());
+        let repeat = find_repeat_by_residue(&freq_changes).unwrap().unwrap();
+        assert_eq!(Some(repeat.clone()), find_repeat(&freq_changes).unwrap());
+        assert_eq!(4, repeat.steps);
+    }
+
+    proptest! {
+        #[test]
+        fn find_repeat_proptests(
+            freq_changes in prop::collection::vec(-20..=20, 0..10)
+        ) {
+            let repeat = naive_find_repeat(&freq_changes);
+            prop_assert_eq!(&repeat, &find_repeat(&freq_changes).unwrap());
+            prop_assert_eq!(&repeat, &find_repeat_by_residue(&freq_changes).unwrap());
+
+            let mut tracker = FrequencyTracker::new();
+            tracker.until_repeat(&freq_changes).unwrap();
+            prop_assert_eq!(&repeat, &tracker.cycled_repeat().unwrap());
+
+            let mut tracker = FrequencyTracker::new();
+            let changes = freq_changes.iter().cycle().take(1_000 * freq_changes.len());
+            let stream_repeat = tracker.until_repeat(changes).unwrap().cloned();
+            prop_assert_eq!(
+                repeat.map(|repeat| (repeat.frequency, repeat.steps)),
+                stream_repeat.map(|repeat| (repeat.frequency, repeat.repeat_index + 1))
+            );
+        }
+    }
+}
@@ -1,56 +1,69 @@
-use std::io;
-use std::io::prelude::*;
+extern crate day01;
+extern crate utils;
 
-fn parse_input() -> Vec<i32> {
-    let mut freq_changes = vec![];
+use day01::{Day01, Frequency, FrequencyTracker};
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::{self, ErrorKind};
+use std::process;
+use utils::{Error, InputSource, Solution};
 
-    let handle = io::stdin();
-    for line in handle.lock().lines() {
-        freq_changes.push(line.unwrap().parse().expect("Illegal input"));
-    }
-
-    freq_changes
-}
+#[cfg(not(feature = "bigint"))]
+const USAGE: &str = "usage: [--width i64|i128] [--input <path>]";
+#[cfg(feature = "bigint")]
+const USAGE: &str = "usage: [--width i64|i128|bigint] [--input <path>]";
 
-fn final_frequency(freq_changes: &[i32]) -> i32 {
-    freq_changes.iter().sum()
+// Explains the answer to the second part as well.
+fn solve<T: Frequency>(source: InputSource) -> Result<(), Error>
+where
+    T::Err: Display,
+{
+    let (frequency, repeat) = match source {
+        // A piped input is streamed rather than kept in memory.
+        InputSource::Stdin => {
+            let stdin = io::stdin();
+            let tracker = FrequencyTracker::<T>::from_reader(stdin.lock())?;
+            let repeat = tracker
+                .cycled_repeat()?
+                .ok_or_else(|| Error::no_solution("the frequencies will never repeat"))?;
+            (tracker.frequency().to_owned(), repeat)
+        }
+        source => {
+            let freq_changes = Day01::<T>::parse(&source.read()?)?;
+            let day = Day01::<T>::default();
+            (day.part1(&freq_changes)?, day.repeat(&freq_changes)?)
+        }
+    };
+    println!("Part 1: {}", frequency);
+    println!("Part 2: {}", repeat.frequency);
+    println!("The frequency {}.", repeat);
+    Ok(())
 }
 
-fn repeating_frequency(freq_changes: &[i32]) -> Option<i32> {
-    let n = freq_changes.len();
-    let mut big_delta = 0;
-    let mut partial_deltas = Vec::with_capacity(n);
-
-    for delta in freq_changes {
-        partial_deltas.push(big_delta);
-        big_delta += delta;
+// The frequencies are computed in the integers given with `--width`, `i64`
+// by default.
+fn run() -> Result<(), Error> {
+    let usage = || Error::Io(io::Error::new(ErrorKind::InvalidInput, USAGE));
+    let mut args = env::args_os().skip(1).collect::<Vec<_>>();
+    let width = match args.iter().position(|arg| arg == "--width") {
+        Some(i) if i + 1 < args.len() => args.drain(i..i + 2).nth(1).unwrap(),
+        Some(_) => return Err(usage()),
+        None => OsString::from("i64"),
+    };
+    let source = utils::input_source::<Day01, _>(1, args)?;
+    match width.to_str() {
+        Some("i64") => solve::<i64>(source),
+        Some("i128") => solve::<i128>(source),
+        #[cfg(feature = "bigint")]
+        Some("bigint") => solve::<day01::BigInt>(source),
+        _ => Err(usage()),
     }
-
-    let mut best_candidate = None;
-
-    for i in 0..n {
-        for j in 0..n {
-            if i != j {
-                let delta = partial_deltas[j] - partial_deltas[i];
-                if (delta % big_delta == 0) && (delta / big_delta >= 0) {
-                    let new_candidate = (delta / big_delta, i);
-                    best_candidate = match best_candidate {
-                        None => Some(new_candidate),
-                        Some(best_candidate) => Some(best_candidate.min(new_candidate)),
-                    };
-                }
-            }
-        }
-    }
-
-    best_candidate.map(|(iterations, index)| iterations * big_delta + partial_deltas[index])
 }
 
 fn main() {
-    let input = parse_input();
-    println!("The final frequency is {}.", final_frequency(&input));
-    match repeating_frequency(&input) {
-        Some(frequency) => println!("The first repeating frequency is {}.", frequency),
-        None => println!("The frequencies will never repeat!"),
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        process::exit(1);
     }
 }
@@ -0,0 +1,415 @@
+extern crate utils;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use utils::{Error, Solution};
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, Error> {
+    Ok(input.lines().map(|line| line.trim().to_owned()).collect())
+}
+
+pub fn frequencies<T>(seq: T) -> HashMap<T::Item, u32>
+where
+    T: Iterator,
+    T::Item: Eq + Hash,
+{
+    let mut freqs = HashMap::new();
+
+    for x in seq {
+        let freq = freqs.entry(x).or_insert(0);
+        *freq += 1;
+    }
+
+    freqs
+}
+
+pub fn checksum(codes: &[String]) -> u32 {
+    let mut doubles = 0;
+    let mut triples = 0;
+
+    for code in codes {
+        let freqs = frequencies(code.chars());
+        if freqs.values().any(|&f| f == 2) {
+            doubles += 1;
+        }
+        if freqs.values().any(|&f| f == 3) {
+            triples += 1;
+        }
+    }
+
+    doubles * triples
+}
+
+pub fn find_boxes(codes: &[String]) -> Option<String> {
+    let code_length = codes.iter().map(|code| code.chars().count()).max()?;
+
+    // Codes of different lengths never leave the same halves around their
+    // `i`-th character.
+    for i in 0..code_length {
+        let freqs = frequencies(codes.iter().filter_map(|code| {
+            let (start, c) = code.char_indices().nth(i)?;
+            Some((&code[..start], &code[start + c.len_utf8()..]))
+        }));
+        if let Some(((left, right), _)) = freqs.iter().find(|&(_, &freq)| freq >= 2) {
+            return Some(format!("{}{}", left, right));
+        }
+    }
+
+    None
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distance {
+    /// The number of positions with different characters, between IDs of the
+    /// same length.
+    Hamming,
+    /// The number of characters changed, removed or added.
+    Levenshtein,
+}
+
+/// A position at which two box IDs differ, given by the index of the
+/// character in each of them, or `None` for a character only in the other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub first: Option<usize>,
+    pub second: Option<usize>,
+}
+
+/// Two box IDs, by their index, and where they differ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NearPair {
+    pub first: usize,
+    pub second: usize,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl NearPair {
+    pub fn distance(&self) -> usize {
+        self.mismatches.len()
+    }
+}
+
+/// Finds every pair of box IDs at most `k` apart, ordered by their indices.
+pub fn find_near_pairs(codes: &[String], k: usize, distance: Distance) -> Vec<NearPair> {
+    let codes = codes
+        .iter()
+        .map(|code| code.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut near_pairs = match distance {
+        Distance::Hamming => hamming_pairs(&codes, k),
+        Distance::Levenshtein => levenshtein_pairs(&codes, k),
+    };
+    near_pairs.sort_unstable_by_key(|pair| (pair.first, pair.second));
+    near_pairs
+}
+
+// Two IDs with at most `k` mismatches, cut into `k + 1` blocks, have at least
+// one block in common, so only the IDs sharing a block are compared.
+fn hamming_pairs(codes: &[Vec<char>], k: usize) -> Vec<NearPair> {
+    let mut blocks: HashMap<(usize, usize, &[char]), Vec<usize>> = HashMap::new();
+    for (index, code) in codes.iter().enumerate() {
+        let length = code.len();
+        for block in 0..=k.min(length) {
+            let (start, end) = (block * length / (k + 1), (block + 1) * length / (k + 1));
+            blocks
+                .entry((length, block, &code[start..end]))
+                .or_default()
+                .push(index);
+        }
+    }
+
+    let mut compared = HashSet::new();
+    let mut near_pairs = vec![];
+    for indices in blocks.values() {
+        for (i, &first) in indices.iter().enumerate() {
+            for &second in &indices[i + 1..] {
+                if !compared.insert((first, second)) {
+                    continue;
+                }
+                let mismatches = hamming_mismatches(&codes[first], &codes[second], k);
+                if let Some(mismatches) = mismatches {
+                    near_pairs.push(NearPair {
+                        first,
+                        second,
+                        mismatches,
+                    });
+                }
+            }
+        }
+    }
+    near_pairs
+}
+
+fn hamming_mismatches(a: &[char], b: &[char], k: usize) -> Option<Vec<Mismatch>> {
+    let mut mismatches = vec![];
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        if x != y {
+            if mismatches.len() == k {
+                return None;
+            }
+            mismatches.push(Mismatch {
+                first: Some(i),
+                second: Some(i),
+            });
+        }
+    }
+    Some(mismatches)
+}
+
+fn levenshtein_pairs(codes: &[Vec<char>], k: usize) -> Vec<NearPair> {
+    let mut near_pairs = vec![];
+    for (first, a) in codes.iter().enumerate() {
+        for (second, b) in codes.iter().enumerate().skip(first + 1) {
+            if a.len().max(b.len()) - a.len().min(b.len()) > k {
+                continue;
+            }
+            if let Some(mismatches) = levenshtein_mismatches(a, b, k) {
+                near_pairs.push(NearPair {
+                    first,
+                    second,
+                    mismatches,
+                });
+            }
+        }
+    }
+    near_pairs
+}
+
+// Fills in the edit distances between all the prefixes of the IDs, giving up
+// as soon as a whole row is over `k`, and walks back along the cheapest edits,
+// preferring changed characters to removed or added ones.
+fn levenshtein_mismatches(a: &[char], b: &[char], k: usize) -> Option<Vec<Mismatch>> {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        distances[i][0] = i;
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + (a[i - 1] != b[j - 1]) as usize;
+            let deletion = distances[i - 1][j] + 1;
+            let insertion = distances[i][j - 1] + 1;
+            distances[i][j] = substitution.min(deletion).min(insertion);
+        }
+        if distances[i].iter().all(|&distance| distance > k) {
+            return None;
+        }
+    }
+    if distances[a.len()][b.len()] > k {
+        return None;
+    }
+
+    let mut mismatches = vec![];
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        let distance = distances[i][j];
+        if i > 0 && j > 0 && distances[i - 1][j - 1] + (a[i - 1] != b[j - 1]) as usize == distance {
+            if a[i - 1] != b[j - 1] {
+                mismatches.push(Mismatch {
+                    first: Some(i - 1),
+                    second: Some(j - 1),
+                });
+            }
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && distances[i - 1][j] + 1 == distance {
+            mismatches.push(Mismatch {
+                first: Some(i - 1),
+                second: None,
+            });
+            i -= 1;
+        } else {
+            mismatches.push(Mismatch {
+                first: None,
+                second: Some(j - 1),
+            });
+            j -= 1;
+        }
+    }
+    mismatches.reverse();
+    Some(mismatches)
+}
+
+#[derive(Debug, Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, codes: &Vec<String>) -> Result<u32, Error> {
+        Ok(checksum(codes))
+    }
+
+    fn part2(&self, codes: &Vec<String>) -> Result<String, Error> {
+        find_boxes(codes).ok_or_else(|| Error::no_solution("no matching boxes found"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/example1.txt");
+    const EXAMPLE2: &str = include_str!("../fixtures/example2.txt");
+    const NON_ASCII: &str = include_str!("../fixtures/non_ascii.txt");
+    const MIXED_LENGTHS: &str = include_str!("../fixtures/mixed_lengths.txt");
+
+    #[test]
+    fn part1_tests() {
+        let codes = Day02::parse(EXAMPLE1).unwrap();
+        assert_eq!(12, Day02.part1(&codes).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let codes = Day02::parse(EXAMPLE2).unwrap();
+        assert_eq!("fgij", Day02.part2(&codes).unwrap());
+        let codes = ["abc", "abcd", "xbcd", "ab"].map(String::from);
+        assert_eq!(Some("bcd".to_owned()), find_boxes(&codes));
+    }
+
+    #[test]
+    fn non_ascii_tests() {
+        let codes = Day02::parse(NON_ASCII).unwrap();
+        assert_eq!(1, Day02.part1(&codes).unwrap());
+        assert_eq!("čá", Day02.part2(&codes).unwrap());
+        assert_eq!(
+            vec![mismatch(Some(2), Some(2))],
+            find_near_pairs(&codes, 1, Distance::Hamming)[0].mismatches
+        );
+        let near_pairs = find_near_pairs(&codes, 1, Distance::Levenshtein);
+        assert_eq!(
+            (3, 5, vec![mismatch(Some(0), None)]),
+            (
+                near_pairs[1].first,
+                near_pairs[1].second,
+                near_pairs[1].mismatches.clone()
+            )
+        );
+    }
+
+    fn mismatch(first: Option<usize>, second: Option<usize>) -> Mismatch {
+        Mismatch { first, second }
+    }
+
+    #[test]
+    fn hamming_tests() {
+        let codes = Day02::parse(EXAMPLE2).unwrap();
+        let near_pairs = find_near_pairs(&codes, 1, Distance::Hamming);
+        assert_eq!(
+            vec![NearPair {
+                first: 1,
+                second: 4,
+                mismatches: vec![mismatch(Some(2), Some(2))],
+            }],
+            near_pairs
+        );
+        let near_pairs = find_near_pairs(&codes, 2, Distance::Hamming);
+        assert_eq!(
+            vec![(0, 5, 2), (1, 4, 1)],
+            near_pairs
+                .iter()
+                .map(|pair| (pair.first, pair.second, pair.distance()))
+                .collect::<Vec<_>>()
+        );
+        let codes = ["ab", "ab", "abc", "", ""].map(String::from);
+        let near_pairs = find_near_pairs(&codes, 0, Distance::Hamming);
+        assert_eq!(
+            vec![(0, 1), (3, 4)],
+            near_pairs
+                .iter()
+                .map(|pair| (pair.first, pair.second))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn hamming_generated_tests() {
+        let input = utils::generate::generate(2, 7).unwrap();
+        let codes = Day02::parse(&input).unwrap();
+        for k in 0..4 {
+            let mut expected = vec![];
+            for (first, a) in codes.iter().enumerate() {
+                for (second, b) in codes.iter().enumerate().skip(first + 1) {
+                    let distance = a.chars().zip(b.chars()).filter(|(x, y)| x != y).count();
+                    if distance <= k {
+                        expected.push((first, second, distance));
+                    }
+                }
+            }
+            let near_pairs = find_near_pairs(&codes, k, Distance::Hamming)
+                .iter()
+                .map(|pair| (pair.first, pair.second, pair.distance()))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, near_pairs);
+        }
+    }
+
+    #[test]
+    fn levenshtein_tests() {
+        let codes = ["abcde", "abde", "abxde", "bcde"].map(String::from);
+        let near_pairs = find_near_pairs(&codes, 1, Distance::Levenshtein);
+        assert_eq!(
+            vec![
+                NearPair {
+                    first: 0,
+                    second: 1,
+                    mismatches: vec![mismatch(Some(2), None)],
+                },
+                NearPair {
+                    first: 0,
+                    second: 2,
+                    mismatches: vec![mismatch(Some(2), Some(2))],
+                },
+                NearPair {
+                    first: 0,
+                    second: 3,
+                    mismatches: vec![mismatch(Some(0), None)],
+                },
+                NearPair {
+                    first: 1,
+                    second: 2,
+                    mismatches: vec![mismatch(None, Some(2))],
+                },
+            ],
+            near_pairs
+        );
+        let near_pairs = find_near_pairs(&codes, 2, Distance::Levenshtein);
+        assert_eq!(6, near_pairs.len());
+        assert_eq!(
+            vec![mismatch(Some(0), Some(0)), mismatch(Some(1), Some(1))],
+            near_pairs[4].mismatches
+        );
+        assert!(find_near_pairs(&codes, 0, Distance::Levenshtein).is_empty());
+    }
+
+    #[test]
+    fn mixed_lengths_tests() {
+        let codes = parse_input(MIXED_LENGTHS).unwrap();
+        assert_eq!(6, codes.len());
+        let near_pairs = find_near_pairs(&codes, 1, Distance::Levenshtein);
+        assert_eq!(
+            vec![(0, 1), (0, 3), (1, 3), (2, 4)],
+            near_pairs
+                .iter()
+                .map(|pair| (pair.first, pair.second))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec![mismatch(Some(2), None)], near_pairs[3].mismatches);
+        let near_pairs = find_near_pairs(&codes, 1, Distance::Hamming);
+        assert_eq!(
+            vec![(0, 3)],
+            near_pairs
+                .iter()
+                .map(|pair| (pair.first, pair.second))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some("abde".to_owned()), find_boxes(&codes));
+    }
+}
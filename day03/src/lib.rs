@@ -0,0 +1,135 @@
+#[macro_use]
+extern crate utils;
+
+use utils::{parse_lines, Error, Grid, Rect, Solution};
+
+#[derive(PartialEq, Eq)]
+pub struct Claim {
+    pub id: i32,
+    pub rect: Rect,
+}
+
+line_parser! {
+    pub fn parse_claim -> Claim {
+        pattern: r"#(?P<id>\d+) @ (?P<left>\d+),(?P<top>\d+): (?P<width>\d+)x(?P<height>\d+)",
+        expected: "a claim like '#1 @ 1,3: 4x4'",
+        |id: i32, left: i64, top: i64, width: i64, height: i64| Claim {
+            id,
+            rect: claim_rect(left, top, width, height)?,
+        }
+    }
+}
+
+fn claim_rect(left: i64, top: i64, width: i64, height: i64) -> Result<Rect, Error> {
+    if left.checked_add(width).is_none() || top.checked_add(height).is_none() {
+        return Err(Error::parse(1, 1, "the claim reaches too far"));
+    }
+    Ok(Rect::new(left, top, width, height))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Claim>, Error> {
+    parse_lines(input, parse_claim)
+}
+
+// Far more than the thousand inches square of the puzzle, but small enough to
+// keep the whole fabric in memory.
+const MAX_FABRIC_AREA: i64 = 1 << 24;
+
+pub fn fabric(claims: &[Claim]) -> Result<Grid<u32>, Error> {
+    let bounds = claims.iter().fold(Rect::new(0, 0, 0, 0), |bounds, claim| {
+        bounds.union(&claim.rect)
+    });
+    match bounds.width().checked_mul(bounds.height()) {
+        Some(area) if area <= MAX_FABRIC_AREA => (),
+        _ => {
+            return Err(Error::invalid(format!(
+                "the claims cover more than {} square inches of fabric",
+                MAX_FABRIC_AREA
+            )))
+        }
+    }
+    let mut fabric = Grid::with_bounds(bounds, 0);
+
+    for claim in claims {
+        for inch in claim.rect.points() {
+            fabric[inch] += 1;
+        }
+    }
+
+    Ok(fabric)
+}
+
+pub fn contested_inches(fabric: &Grid<u32>) -> usize {
+    fabric.values().filter(|&&n_claims| n_claims > 1).count()
+}
+
+pub fn safe_claim<'a>(claims: &'a [Claim], fabric: &Grid<u32>) -> Option<&'a Claim> {
+    claims
+        .iter()
+        .find(|claim| claim.rect.points().all(|inch| fabric[inch] == 1))
+}
+
+#[derive(Debug, Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Claim>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, claims: &Vec<Claim>) -> Result<usize, Error> {
+        Ok(contested_inches(&fabric(claims)?))
+    }
+
+    fn part2(&self, claims: &Vec<Claim>) -> Result<i32, Error> {
+        match safe_claim(claims, &fabric(claims)?) {
+            Some(claim) => Ok(claim.id),
+            None => Err(Error::no_solution("all the claims overlap")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let claims = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(4, Day03.part1(&claims).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let claims = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(3, Day03.part2(&claims).unwrap());
+    }
+
+    #[test]
+    fn parse_tests() {
+        let error = Day03::parse("#1 @ 1,3: 4x4\n#2 @ 1,9223372036854775807: 4x4\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            "line 2, column 1: the claim reaches too far",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn fabric_tests() {
+        let claims = Day03::parse("#1 @ 1,3: 4x4\n#2 @ 5000,0: 4000x4000\n").unwrap();
+        assert_eq!(
+            "invalid input: the claims cover more than 16777216 square inches of fabric",
+            Day03.part1(&claims).err().unwrap().to_string()
+        );
+        let claims = Day03::parse("#1 @ 0,0: 9223372036854775807x2\n").unwrap();
+        assert!(Day03.part2(&claims).is_err());
+    }
+}
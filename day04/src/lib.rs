@@ -0,0 +1,234 @@
+extern crate chrono;
+extern crate utils;
+
+use chrono::prelude::*;
+use std::collections::HashMap;
+use utils::{Error, LineParser, Solution};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Event {
+    pub date_time: NaiveDateTime,
+    pub event_type: EventType,
+}
+
+pub type GuardID = u32;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum EventType {
+    ShiftChange(GuardID),
+    FallingAsleep,
+    WakingUp,
+}
+
+pub struct TimeInterval {
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Opportunity {
+    pub guard_id: GuardID,
+    pub minute: u32,
+}
+
+impl Opportunity {
+    /// The ID of the guard multiplied by the minute.
+    pub fn answer(&self) -> Result<u32, Error> {
+        self.guard_id.checked_mul(self.minute).ok_or_else(|| {
+            Error::invalid(format!(
+                "guard #{} at minute {} gives an answer too large to compute",
+                self.guard_id, self.minute
+            ))
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Event>, Error> {
+    let line_parser = LineParser::new(
+        r"(?x)
+\[ (?P<date_time>\d{4}-\d{2}-\d{2} \s+ \d{2}:\d{2}) \]
+\s+
+(?: (?P<shift_change> Guard\ \#(?P<guard_id>\d+)\ begins\ shift)
+  | (?P<falling_asleep> falls\ asleep)
+  | (?P<waking_up> wakes\ up))",
+        "a record like '[1518-11-01 00:00] Guard #10 begins shift'",
+    );
+
+    let mut events = line_parser.parse_lines(input, |fields| {
+        let date_time = fields.get_with("date_time", |date_time| {
+            NaiveDateTime::parse_from_str(date_time, "%Y-%m-%d %H:%M")
+        })?;
+        let event_type = if fields.has("shift_change") {
+            EventType::ShiftChange(fields.get("guard_id")?)
+        } else if fields.has("falling_asleep") {
+            EventType::FallingAsleep
+        } else {
+            EventType::WakingUp
+        };
+        Ok(Event {
+            date_time,
+            event_type,
+        })
+    })?;
+    events.sort_unstable();
+    Ok(events)
+}
+
+pub fn record_naps(events: &[Event]) -> Result<HashMap<GuardID, Vec<TimeInterval>>, Error> {
+    let mut sleep_times: HashMap<GuardID, Vec<TimeInterval>> = HashMap::new();
+    let mut current_guard = None;
+    let mut fell_asleep = None;
+
+    for event in events {
+        match (event.event_type, current_guard, fell_asleep) {
+            (EventType::ShiftChange(_), Some(guard), Some(_)) => {
+                return Err(Error::invalid(format!(
+                    "guard #{} is still asleep when the shift changes at {}",
+                    guard, event.date_time
+                )));
+            }
+            (EventType::ShiftChange(new_guard), _, _) => {
+                current_guard = Some(new_guard);
+            }
+            (EventType::FallingAsleep, Some(_), None) => {
+                fell_asleep = Some(event.date_time);
+            }
+            (EventType::WakingUp, Some(guard), Some(from)) => {
+                let nap = TimeInterval {
+                    from,
+                    to: event.date_time,
+                };
+                sleep_times.entry(guard).or_default().push(nap);
+                fell_asleep = None;
+            }
+            (_, None, _) => {
+                return Err(Error::invalid(format!(
+                    "no guard is on duty at {}",
+                    event.date_time
+                )));
+            }
+            (EventType::FallingAsleep, Some(guard), Some(_)) => {
+                return Err(Error::invalid(format!(
+                    "guard #{} falls asleep at {} while already asleep",
+                    guard, event.date_time
+                )));
+            }
+            (EventType::WakingUp, Some(guard), None) => {
+                return Err(Error::invalid(format!(
+                    "guard #{} wakes up at {} without having fallen asleep",
+                    guard, event.date_time
+                )));
+            }
+        }
+    }
+
+    Ok(sleep_times)
+}
+
+pub fn find_best_opportunity_s1(events: &[Event]) -> Result<Opportunity, Error> {
+    let guards_naps = record_naps(events)?;
+
+    let sleepiest_guard = *guards_naps
+        .iter()
+        .max_by_key(|&(_, naps)| {
+            naps.iter()
+                .map(|&TimeInterval { from, to }| (to - from).num_minutes())
+                .sum::<i64>()
+        })
+        .ok_or_else(|| Error::no_solution("no guard ever falls asleep"))?
+        .0;
+
+    let mut sleepy_minutes = [0; 60];
+    for nap in &guards_naps[&sleepiest_guard] {
+        for sleepy_minute in nap.from.minute()..nap.to.minute() {
+            sleepy_minutes[sleepy_minute as usize] += 1;
+        }
+    }
+
+    let sleepiest_minute = sleepy_minutes
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, &s)| s)
+        .unwrap()
+        .0;
+    Ok(Opportunity {
+        guard_id: sleepiest_guard,
+        minute: sleepiest_minute as u32,
+    })
+}
+
+pub fn find_best_opportunity_s2(events: &[Event]) -> Result<Opportunity, Error> {
+    let guards_naps = record_naps(events)?;
+
+    let mut opportunities = HashMap::new();
+    for (guard_id, naps) in guards_naps {
+        for nap in naps {
+            for minute in nap.from.minute()..nap.to.minute() {
+                *opportunities
+                    .entry(Opportunity { guard_id, minute })
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    opportunities
+        .into_iter()
+        .max_by_key(|&(_, score)| score)
+        .map(|(opportunity, _)| opportunity)
+        .ok_or_else(|| Error::no_solution("no guard ever falls asleep"))
+}
+
+#[derive(Debug, Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Event>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Event>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, events: &Vec<Event>) -> Result<u32, Error> {
+        find_best_opportunity_s1(events)?.answer()
+    }
+
+    fn part2(&self, events: &Vec<Event>) -> Result<u32, Error> {
+        find_best_opportunity_s2(events)?.answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let events = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(240, Day04.part1(&events).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let events = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(4455, Day04.part2(&events).unwrap());
+    }
+
+    #[test]
+    fn answer_tests() {
+        let events = Day04::parse(
+            "[1518-11-01 00:00] Guard #4294967295 begins shift\n\
+             [1518-11-01 00:05] falls asleep\n\
+             [1518-11-01 00:06] wakes up\n",
+        )
+        .unwrap();
+        assert_eq!(
+            "invalid input: guard #4294967295 at minute 5 gives an answer too large to compute",
+            Day04.part1(&events).err().unwrap().to_string()
+        );
+        assert!(Day04.part2(&events).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+extern crate proptest;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+extern crate utils;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use utils::{Error, Solution};
+
+pub fn parse_input(input: &str) -> Result<String, Error> {
+    let polymer = input.trim();
+    match polymer
+        .chars()
+        .enumerate()
+        .find(|(_, unit)| !unit.is_ascii_alphabetic())
+    {
+        Some((column, unit)) => Err(Error::parse(
+            1,
+            column + 1,
+            format!("unexpected unit '{}' in polymer", unit),
+        )),
+        None => Ok(polymer.to_owned()),
+    }
+}
+
+pub fn is_opposite_unit(a: char, b: char) -> bool {
+    (a.to_ascii_uppercase() == b && b.to_ascii_lowercase() == a)
+        || (a.to_ascii_lowercase() == b && b.to_ascii_uppercase() == a)
+}
+
+pub fn reduce_polymer<I>(polymer: I, capacity: usize) -> usize
+where
+    I: Iterator<Item = char>,
+{
+    let mut unit_stack: Vec<char> = Vec::with_capacity(capacity);
+
+    for unit in polymer {
+        match unit_stack.pop() {
+            Some(opposite_unit) if is_opposite_unit(unit, opposite_unit) => (),
+            Some(other_unit) => {
+                unit_stack.push(other_unit);
+                unit_stack.push(unit);
+            }
+            None => unit_stack.push(unit),
+        }
+    }
+
+    unit_stack.len()
+}
+
+pub fn kill_unit<I>(polymer: I, lower_unit: char, upper_unit: char) -> impl Iterator<Item = char>
+where
+    I: Iterator<Item = char>,
+{
+    polymer.filter(move |&unit| unit != lower_unit && unit != upper_unit)
+}
+
+pub fn find_problematic_unit(polymer: &str) -> (String, usize) {
+    #[cfg(not(feature = "parallel"))]
+    let units = b'A'..=b'Z';
+    #[cfg(feature = "parallel")]
+    let units = (b'A'..=b'Z').into_par_iter();
+
+    units
+        .map(|upper_unit| {
+            let upper_unit = upper_unit as char;
+            let lower_unit = upper_unit.to_ascii_lowercase();
+            let clean_polymer = kill_unit(polymer.chars(), lower_unit, upper_unit);
+            let final_length = reduce_polymer(clean_polymer, polymer.len());
+            (format!("{}/{}", upper_unit, lower_unit), final_length)
+        })
+        // Ties go to the first unit, even when trying them in parallel.
+        .min_by(|(unit_a, length_a), (unit_b, length_b)| {
+            length_a.cmp(length_b).then(unit_a.cmp(unit_b))
+        })
+        .unwrap()
+}
+
+#[derive(Debug, Default)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, polymer: &String) -> Result<usize, Error> {
+        Ok(reduce_polymer(polymer.chars(), polymer.len()))
+    }
+
+    fn part2(&self, polymer: &String) -> Result<usize, Error> {
+        let (_, length) = find_problematic_unit(polymer);
+        Ok(length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Removes the first pair of opposite units until there are none left.
+    fn naive_reduce_polymer(polymer: &str) -> usize {
+        let mut polymer = polymer.to_owned();
+        loop {
+            let chars = polymer.chars().collect::<Vec<_>>();
+            match chars.windows(2).position(|w| is_opposite_unit(w[0], w[1])) {
+                Some(i) => {
+                    polymer.replace_range(i..i + 2, "");
+                }
+                None => return polymer.len(),
+            }
+        }
+    }
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let polymer = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(10, Day05.part1(&polymer).unwrap());
+        assert_eq!(0, reduce_polymer("aA".chars(), 2));
+        assert_eq!(0, reduce_polymer("abBA".chars(), 4));
+        assert_eq!(4, reduce_polymer("abAB".chars(), 4));
+        assert_eq!(6, reduce_polymer("aabAAB".chars(), 6));
+    }
+
+    #[test]
+    fn part2_tests() {
+        let polymer = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(4, Day05.part2(&polymer).unwrap());
+        assert_eq!(("C/c".to_owned(), 4), find_problematic_unit(&polymer));
+    }
+
+    proptest! {
+        #[test]
+        fn reduce_polymer_proptests(polymer in "[aAbBcC]{0,40}") {
+            prop_assert_eq!(
+                naive_reduce_polymer(&polymer),
+                reduce_polymer(polymer.chars(), polymer.len())
+            );
+            let naive_shortest = "abc"
+                .chars()
+                .map(|unit| {
+                    let upper_unit = unit.to_ascii_uppercase();
+                    naive_reduce_polymer(&polymer.replace([unit, upper_unit], ""))
+                })
+                .min()
+                .unwrap();
+            let (_, shortest) = find_problematic_unit(&polymer);
+            prop_assert_eq!(naive_shortest.min(naive_reduce_polymer(&polymer)), shortest);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[macro_use]
+extern crate utils;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use utils::{flood_fill, parse_lines, Error, Point2, Rect, Solution, NEIGHBORS4};
+
+#[derive(Debug)]
+pub enum PointClass<'a> {
+    NearestTo(&'a Point2),
+    Tied,
+}
+
+line_parser! {
+    pub fn parse_point -> Point2 {
+        pattern: r"(?P<x>\d+),\s+(?P<y>\d+)",
+        expected: "coordinates like '1, 6'",
+        |x: i64, y: i64| Point2::new(x, y)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Point2>, Error> {
+    let locations = parse_lines(input, parse_point)?;
+    if locations.len() < 2 {
+        return Err(Error::invalid("at least two coordinates are needed"));
+    }
+    Ok(locations)
+}
+
+pub fn distance(a: &Point2, b: &Point2) -> u32 {
+    a.manhattan_distance(*b) as u32
+}
+
+pub fn classify<'a>(locations: &'a [Point2], point: &Point2) -> PointClass<'a> {
+    let mut locations_with_distances = locations
+        .iter()
+        .map(|location| (location, distance(point, location)))
+        .collect::<Vec<_>>();
+
+    locations_with_distances.sort_by_key(|&(_, dist)| dist);
+
+    if locations_with_distances[0].1 == locations_with_distances[1].1 {
+        PointClass::Tied
+    } else {
+        PointClass::NearestTo(locations_with_distances[0].0)
+    }
+}
+
+fn explore_part1(bounds: &Rect, locations: &[Point2], start: &Point2) -> Option<u32> {
+    // A zone reaching past the bounds of the locations goes on forever.
+    let in_zone = |loc: &Point2| match classify(locations, loc) {
+        PointClass::NearestTo(n_loc) if n_loc == start && !bounds.contains(*loc) => None,
+        PointClass::NearestTo(n_loc) => Some(n_loc == start),
+        PointClass::Tied => Some(false),
+    };
+    let neighbors = |&loc: &Point2| NEIGHBORS4.iter().map(move |&offset| loc + offset);
+    flood_fill(*start, in_zone, neighbors).map(|size| size as u32)
+}
+
+pub fn find_size_safest_zone(locations: &[Point2]) -> Option<u32> {
+    let bounds = Rect::bounding(locations.iter().cloned())?;
+    #[cfg(not(feature = "parallel"))]
+    let starts = locations.iter();
+    #[cfg(feature = "parallel")]
+    let starts = locations.par_iter();
+
+    starts
+        .filter_map(|start| explore_part1(&bounds, locations, start))
+        .max()
+}
+
+fn explore_part2(locations: &[Point2], limit: u32, start: &Point2) -> u32 {
+    let within_limit = |loc: &Point2| {
+        Some(
+            locations
+                .iter()
+                .map(|location| distance(loc, location))
+                .sum::<u32>()
+                < limit,
+        )
+    };
+    let neighbors = |&loc: &Point2| NEIGHBORS4.iter().map(move |&offset| loc + offset);
+    flood_fill(*start, within_limit, neighbors).unwrap() as u32
+}
+
+pub fn centroid(locations: &[Point2]) -> Point2 {
+    let mut centroid = Point2::ORIGIN;
+    for point in locations {
+        centroid.x += point.x;
+        centroid.y += point.y;
+    }
+    centroid.x /= locations.len() as i64;
+    centroid.y /= locations.len() as i64;
+    centroid
+}
+
+pub fn find_brave_zone_size(locations: &[Point2], max_total_distance: u32) -> u32 {
+    explore_part2(locations, max_total_distance, &centroid(locations))
+}
+
+#[derive(Debug)]
+pub struct Day06 {
+    pub max_total_distance: u32,
+}
+
+impl Default for Day06 {
+    fn default() -> Day06 {
+        Day06 {
+            max_total_distance: 10_000,
+        }
+    }
+}
+
+impl Solution for Day06 {
+    type Input = Vec<Point2>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Point2>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, locations: &Vec<Point2>) -> Result<u32, Error> {
+        find_size_safest_zone(locations)
+            .ok_or_else(|| Error::no_solution("could not find any safe zone"))
+    }
+
+    fn part2(&self, locations: &Vec<Point2>) -> Result<u32, Error> {
+        Ok(find_brave_zone_size(locations, self.max_total_distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let locations = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(17, Day06::default().part1(&locations).unwrap());
+    }
+
+    #[test]
+    fn explore_part1_tests() {
+        let locations = Day06::parse(EXAMPLE).unwrap();
+        let bounds = Rect::bounding(locations.iter().cloned()).unwrap();
+        // The searches for the infinite zones are aborted.
+        assert_eq!(
+            vec![None, None, None, Some(9), Some(17), None],
+            locations
+                .iter()
+                .map(|start| explore_part1(&bounds, &locations, start))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn part2_tests() {
+        let locations = Day06::parse(EXAMPLE).unwrap();
+        let day = Day06 {
+            max_total_distance: 32,
+        };
+        assert_eq!(16, day.part2(&locations).unwrap());
+    }
+}
@@ -0,0 +1,191 @@
+#[macro_use]
+extern crate utils;
+
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use utils::{parse_lines, topological_sort, Error, Solution};
+
+pub type NodeID = char;
+
+#[derive(Clone)]
+pub struct Graph {
+    pub nodes: HashSet<NodeID>,
+    pub successors: HashMap<NodeID, HashSet<NodeID>>,
+    pub predecessors: HashMap<NodeID, HashSet<NodeID>>,
+}
+
+line_parser! {
+    /// Parses an instruction into the edge from the step that must be finished
+    /// first to the one that waits for it.
+    pub fn parse_edge -> (NodeID, NodeID) {
+        pattern: r"Step (?P<from>[A-Z]) must be finished before step (?P<to>[A-Z]) can begin\.",
+        expected: "an instruction like 'Step C must be finished before step A can begin.'",
+        |from: NodeID, to: NodeID| (from, to)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Graph, Error> {
+    let mut nodes: HashSet<NodeID> = HashSet::new();
+    let mut successors: HashMap<NodeID, HashSet<NodeID>> = HashMap::new();
+    let mut predecessors: HashMap<NodeID, HashSet<NodeID>> = HashMap::new();
+    for (from, to) in parse_lines(input, parse_edge)? {
+        nodes.insert(from);
+        nodes.insert(to);
+        successors.entry(from).or_default().insert(to);
+        successors.entry(to).or_default();
+        predecessors.entry(from).or_default();
+        predecessors.entry(to).or_default().insert(from);
+    }
+
+    let graph = Graph {
+        nodes,
+        successors,
+        predecessors,
+    };
+
+    if topological_order(&graph).len() < graph.nodes.len() {
+        return Err(Error::invalid("the steps depend on each other in a cycle"));
+    }
+
+    Ok(graph)
+}
+
+pub fn topological_order(graph: &Graph) -> Vec<NodeID> {
+    let nodes = graph.nodes.iter().cloned().collect::<Vec<NodeID>>();
+    topological_sort(&nodes, |node| graph.successors[node].iter().cloned())
+}
+
+enum WorkerState {
+    Working(NodeID, u32),
+    Idle,
+}
+
+pub fn step_length(step: NodeID, base_duration: u32) -> u32 {
+    base_duration + 1 + step.to_digit(36).unwrap() - 'A'.to_digit(36).unwrap()
+}
+
+fn step_remaining_work(graph: &Graph, step: NodeID, base_duration: u32) -> u32 {
+    step_length(step, base_duration)
+        + graph.successors[&step]
+            .iter()
+            .map(|&succ| step_remaining_work(graph, succ, base_duration))
+            .max()
+            .unwrap_or(0)
+}
+
+pub fn work(mut graph: Graph, n_workers: usize, base_duration: u32) -> u32 {
+    let mut time = 0;
+    let mut workers = Vec::with_capacity(n_workers);
+    for _ in 0..n_workers {
+        workers.push(WorkerState::Idle);
+    }
+    let mut next: BinaryHeap<(u32, NodeID)> = graph
+        .nodes
+        .iter()
+        .filter(|&node| graph.predecessors[node].is_empty())
+        .map(|&node| (step_remaining_work(&graph, node, base_duration), node))
+        .collect();
+
+    while !graph.nodes.is_empty() {
+        for worker in workers.iter_mut() {
+            if let WorkerState::Idle = worker {
+                if let Some((_, step)) = next.pop() {
+                    *worker = WorkerState::Working(step, step_length(step, base_duration))
+                }
+            }
+        }
+
+        time += 1;
+
+        for worker in workers.iter_mut() {
+            *worker = match *worker {
+                WorkerState::Working(step, 1) => {
+                    for &next_step in graph.successors[&step].iter() {
+                        graph
+                            .predecessors
+                            .get_mut(&next_step)
+                            .unwrap()
+                            .remove(&step);
+                        if graph.predecessors[&next_step].is_empty() {
+                            next.push((
+                                step_remaining_work(&graph, next_step, base_duration),
+                                next_step,
+                            ));
+                        }
+                    }
+                    graph.nodes.remove(&step);
+                    graph.successors.remove(&step);
+                    graph.predecessors.remove(&step);
+                    WorkerState::Idle
+                }
+                WorkerState::Working(step, time_remaining) => {
+                    WorkerState::Working(step, time_remaining - 1)
+                }
+                WorkerState::Idle => WorkerState::Idle,
+            }
+        }
+    }
+
+    time
+}
+
+#[derive(Debug)]
+pub struct Day07 {
+    pub n_workers: usize,
+    pub base_duration: u32,
+}
+
+impl Default for Day07 {
+    fn default() -> Day07 {
+        Day07 {
+            n_workers: 5,
+            base_duration: 60,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    type Input = Graph;
+    type Answer1 = String;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Graph, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, graph: &Graph) -> Result<String, Error> {
+        Ok(topological_order(graph).into_iter().collect())
+    }
+
+    fn part2(&self, graph: &Graph) -> Result<u32, Error> {
+        if self.n_workers == 0 {
+            return Err(Error::invalid("there must be at least one worker"));
+        }
+        Ok(work(graph.clone(), self.n_workers, self.base_duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let graph = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!("CABDFE", Day07::default().part1(&graph).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let graph = Day07::parse(EXAMPLE).unwrap();
+        let day = Day07 {
+            n_workers: 2,
+            base_duration: 0,
+        };
+        assert_eq!(15, day.part2(&graph).unwrap());
+        assert_eq!(253, Day07::default().part2(&graph).unwrap());
+    }
+}
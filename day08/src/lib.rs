@@ -0,0 +1,213 @@
+extern crate utils;
+
+use std::fmt::Display;
+use std::str::FromStr;
+use utils::{Error, Solution};
+
+pub struct Tree {
+    pub children: Vec<Tree>,
+    pub metadata: Vec<u32>,
+}
+
+/// A number from the input together with the column at which it starts.
+pub type Token<'a> = (usize, &'a str);
+
+fn parse_number<T>(inputs: &[Token], index: usize, what: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match inputs.get(index) {
+        Some(&(column, number)) => number
+            .parse()
+            .map_err(|e| Error::parse(1, column, format!("invalid {} '{}': {}", what, number, e))),
+        None => Err(Error::parse(
+            1,
+            inputs
+                .last()
+                .map_or(1, |&(column, number)| column + number.len()),
+            format!("unexpected end of input, expected {}", what),
+        )),
+    }
+}
+
+// The trees are parsed, summed up and dropped recursively, so the deeper ones
+// would overflow the stack.
+const MAX_DEPTH: usize = 1_000;
+
+pub fn parse_tree(inputs: &[Token]) -> Result<(Tree, usize), Error> {
+    parse_subtree(inputs, 0)
+}
+
+fn parse_subtree(inputs: &[Token], depth: usize) -> Result<(Tree, usize), Error> {
+    if depth == MAX_DEPTH {
+        let column = inputs.first().map_or(1, |&(column, _)| column);
+        return Err(Error::parse(
+            1,
+            column,
+            format!("the tree is nested deeper than {} levels", MAX_DEPTH),
+        ));
+    }
+
+    let mut length = 0;
+
+    let n_children = parse_number(inputs, 0, "number of children")?;
+    let n_metadata = parse_number(inputs, 1, "number of metadata entries")?;
+    length += 2;
+
+    let mut children = Vec::new();
+    let mut metadata = Vec::new();
+
+    for _ in 0..n_children {
+        let (child, child_length) = parse_subtree(&inputs[length..], depth + 1)?;
+        children.push(child);
+        length += child_length;
+    }
+
+    for _ in 0..n_metadata {
+        metadata.push(parse_number(inputs, length, "metadata entry")?);
+        length += 1;
+    }
+
+    Ok((Tree { children, metadata }, length))
+}
+
+pub fn parse_input(input: &str) -> Result<Tree, Error> {
+    let mut tokens = Vec::new();
+    let mut column = 1;
+    for number in input.trim_end().split(' ') {
+        tokens.push((column, number));
+        column += number.len() + 1;
+    }
+
+    let (tree, length) = parse_tree(&tokens)?;
+    match tokens.get(length) {
+        Some(&(column, _)) => Err(Error::parse(1, column, "unexpected input after the tree")),
+        None => Ok(tree),
+    }
+}
+
+fn overflow() -> Error {
+    Error::invalid("the sum does not fit in u32")
+}
+
+pub fn metadata_sum(tree: &Tree) -> Result<u32, Error> {
+    let mut sum = tree
+        .metadata
+        .iter()
+        .try_fold(0u32, |sum, &entry| sum.checked_add(entry))
+        .ok_or_else(overflow)?;
+    for child in &tree.children {
+        sum = sum.checked_add(metadata_sum(child)?).ok_or_else(overflow)?;
+    }
+    Ok(sum)
+}
+
+pub fn node_value(tree: &Tree) -> Result<u32, Error> {
+    if tree.children.is_empty() {
+        return metadata_sum(tree);
+    }
+
+    // Every child is valued at most once, however many entries refer to it,
+    // or the deep trees would take exponential time.
+    let mut child_values = vec![None; tree.children.len()];
+    let mut value = 0u32;
+    for &entry in &tree.metadata {
+        let index = match entry.checked_sub(1) {
+            Some(index) if (index as usize) < tree.children.len() => index as usize,
+            _ => continue,
+        };
+        let child_value = match child_values[index] {
+            Some(child_value) => child_value,
+            None => {
+                let child_value = node_value(&tree.children[index])?;
+                child_values[index] = Some(child_value);
+                child_value
+            }
+        };
+        value = value.checked_add(child_value).ok_or_else(overflow)?;
+    }
+    Ok(value)
+}
+
+#[derive(Debug, Default)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Tree;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Tree, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, tree: &Tree) -> Result<u32, Error> {
+        metadata_sum(tree)
+    }
+
+    fn part2(&self, tree: &Tree) -> Result<u32, Error> {
+        node_value(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn part1_tests() {
+        let tree = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(138, Day08.part1(&tree).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let tree = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(66, Day08.part2(&tree).unwrap());
+    }
+
+    #[test]
+    fn parse_tests() {
+        let nested = |depth| {
+            let mut input = "1 1 ".repeat(depth);
+            input.push_str("0 1 7");
+            input.push_str(&" 1".repeat(depth));
+            input
+        };
+        let tree = Day08::parse(&nested(MAX_DEPTH - 1)).unwrap();
+        assert_eq!(7, Day08.part2(&tree).unwrap());
+        assert_eq!(
+            "line 1, column 4001: the tree is nested deeper than 1000 levels",
+            Day08::parse(&nested(MAX_DEPTH)).err().unwrap().to_string()
+        );
+        assert_eq!(
+            "line 1, column 4: unexpected end of input, expected metadata entry",
+            Day08::parse("0 1").err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn overflow_tests() {
+        let tree = Day08::parse("1 1 0 2 4294967295 1 1").unwrap();
+        assert_eq!(
+            "invalid input: the sum does not fit in u32",
+            Day08.part1(&tree).err().unwrap().to_string()
+        );
+        assert!(Day08.part2(&tree).is_err());
+        let tree = Day08::parse("1 2 0 1 4294967295 1 1").unwrap();
+        assert!(Day08.part2(&tree).is_err());
+
+        // Every node refers to its only child twice, doubling the value.
+        let chain = |depth| {
+            let mut input = "1 2 ".repeat(depth);
+            input.push_str("0 1 1");
+            input.push_str(&" 1 1".repeat(depth));
+            Day08::parse(&input).unwrap()
+        };
+        assert_eq!(1 << 20, Day08.part2(&chain(20)).unwrap());
+        assert!(Day08.part2(&chain(MAX_DEPTH - 1)).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+#[cfg(test)]
+extern crate proptest;
+#[macro_use]
+extern crate utils;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use utils::{Error, Solution};
+
+pub struct RingNode(Rc<RingNodeContents>);
+
+struct RingNodeContents {
+    value: u32,
+    ccw: RefCell<Option<RingNode>>,
+    cw: RefCell<Option<RingNode>>,
+}
+
+impl Clone for RingNode {
+    fn clone(&self) -> RingNode {
+        RingNode(Rc::clone(&self.0))
+    }
+}
+
+impl RingNode {
+    pub fn new(value: u32) -> RingNode {
+        let node = RingNode(Rc::new(RingNodeContents {
+            value,
+            ccw: RefCell::new(None),
+            cw: RefCell::new(None),
+        }));
+        *node.0.ccw.borrow_mut() = Some(RingNode(Rc::clone(&node.0)));
+        *node.0.cw.borrow_mut() = Some(RingNode(Rc::clone(&node.0)));
+        node
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0.value
+    }
+
+    pub fn cw(&self) -> RingNode {
+        RingNode(Rc::clone(&self.0.cw.borrow().as_ref().unwrap().0))
+    }
+
+    pub fn ccw(&self) -> RingNode {
+        RingNode(Rc::clone(&self.0.ccw.borrow().as_ref().unwrap().0))
+    }
+
+    pub fn insert_cw(&self, new_value: u32) -> RingNode {
+        let new_node = RingNode(Rc::new(RingNodeContents {
+            value: new_value,
+            ccw: RefCell::new(Some(self.clone())),
+            cw: RefCell::new(Some(self.cw())),
+        }));
+        let cw = self.cw();
+        *self.0.cw.borrow_mut() = Some(RingNode(Rc::clone(&new_node.0)));
+        *cw.0.ccw.borrow_mut() = Some(RingNode(Rc::clone(&new_node.0)));
+        new_node
+    }
+
+    pub fn remove(self) {
+        let ccw = self.ccw();
+        let cw = self.cw();
+        *ccw.0.cw.borrow_mut() = Some(self.cw());
+        *cw.0.ccw.borrow_mut() = Some(self.ccw());
+    }
+
+    pub fn kill_ring(self) {
+        let mut this = self;
+        loop {
+            let next = match this.0.cw.borrow().as_ref() {
+                Some(n) => n.clone(),
+                None => {
+                    break;
+                }
+            };
+            *this.0.cw.borrow_mut() = None;
+            *this.0.ccw.borrow_mut() = None;
+            this = next.clone();
+        }
+    }
+}
+
+pub fn play_marbles(num_players: usize, top_marble: u32) -> u32 {
+    let mut scores = vec![0; num_players];
+    let mut current_marble = RingNode::new(0);
+    let mut current_player = 0;
+
+    for marble in 1..=top_marble {
+        if marble % 23 == 0 {
+            scores[current_player] += marble;
+            for _ in 1..=7 {
+                current_marble = current_marble.ccw();
+            }
+            scores[current_player] += current_marble.value();
+            let new_current_marble = current_marble.cw();
+            current_marble.remove();
+            current_marble = new_current_marble;
+        } else {
+            current_marble = current_marble.cw().insert_cw(marble);
+        }
+        current_player = (current_player + 1) % num_players;
+    }
+
+    current_marble.kill_ring();
+
+    *scores.iter().max().unwrap()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub num_players: usize,
+    pub top_marble: u32,
+}
+
+line_parser! {
+    pub fn parse_game -> Game {
+        pattern: r"^(?P<num_players>\d+) players; last marble is worth (?P<top_marble>\d+) points$",
+        expected: "a game like '9 players; last marble is worth 25 points'",
+        |num_players: usize, top_marble: u32| Game { num_players, top_marble }
+    }
+}
+
+/// The fields that are set replace the ones of the game in the input.
+#[derive(Debug, Default)]
+pub struct Day09 {
+    pub num_players: Option<usize>,
+    pub top_marble: Option<u32>,
+}
+
+impl Day09 {
+    fn play(&self, game: &Game, scale: u32) -> Result<u32, Error> {
+        let num_players = self.num_players.unwrap_or(game.num_players);
+        if num_players == 0 {
+            return Err(Error::invalid("there must be at least one player"));
+        }
+        let top_marble = self
+            .top_marble
+            .unwrap_or(game.top_marble)
+            .checked_mul(scale)
+            .ok_or_else(|| Error::invalid("the last marble is too large"))?;
+        Ok(play_marbles(num_players, top_marble))
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Game;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const DEFAULT_INPUT: Option<&'static str> =
+        Some("405 players; last marble is worth 71700 points\n");
+
+    fn parse(input: &str) -> Result<Game, Error> {
+        let games = utils::parse_lines(input, parse_game)?;
+        match games[..] {
+            [game] => Ok(game),
+            _ => Err(Error::invalid("expected exactly one game")),
+        }
+    }
+
+    fn part1(&self, game: &Game) -> Result<u32, Error> {
+        self.play(game, 1)
+    }
+
+    fn part2(&self, game: &Game) -> Result<u32, Error> {
+        self.play(game, 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Plays with the circle in a `Vec`, the current marble at `current`.
+    fn naive_play_marbles(num_players: usize, top_marble: u32) -> u32 {
+        let mut scores = vec![0; num_players];
+        let mut circle = vec![0];
+        let mut current = 0;
+        for marble in 1..=top_marble {
+            let player = (marble as usize - 1) % num_players;
+            if marble % 23 == 0 {
+                current = (current + circle.len() - 7) % circle.len();
+                scores[player] += marble + circle.remove(current);
+                if current == circle.len() {
+                    current = 0;
+                }
+            } else {
+                current = (current + 1) % circle.len() + 1;
+                circle.insert(current, marble);
+            }
+        }
+        *scores.iter().max().unwrap()
+    }
+
+    #[test]
+    fn ring_tests() {
+        let mut countdown = RingNode::new(1).insert_cw(2).insert_cw(3);
+        for i in (1..=3).rev() {
+            assert_eq!(i, countdown.value());
+            countdown = countdown.ccw();
+        }
+
+        countdown.cw().remove();
+
+        for i in 1..=10 {
+            assert_eq!(i % 2, countdown.value() % 2);
+            countdown = countdown.ccw();
+        }
+    }
+
+    #[test]
+    fn play_marbles_tests() {
+        assert_eq!(32, play_marbles(9, 25));
+        assert_eq!(8317, play_marbles(10, 1618));
+        assert_eq!(146373, play_marbles(13, 7999));
+        assert_eq!(2764, play_marbles(17, 1104));
+        assert_eq!(54718, play_marbles(21, 6111));
+        assert_eq!(37305, play_marbles(30, 5807));
+    }
+
+    #[test]
+    fn parse_tests() {
+        let game = Day09::parse("10 players; last marble is worth 1618 points\n").unwrap();
+        assert_eq!(8317, Day09::default().part1(&game).unwrap());
+        let overridden = Day09 {
+            num_players: Some(9),
+            top_marble: Some(25),
+        };
+        assert_eq!(32, overridden.part1(&game).unwrap());
+        assert!(Day09::parse("").is_err());
+        assert!(Day09::parse("10 players; last marble is worth 1618\n").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn play_marbles_proptests(num_players in 1..30usize, top_marble in 0..2_000u32) {
+            prop_assert_eq!(
+                naive_play_marbles(num_players, top_marble),
+                play_marbles(num_players, top_marble)
+            );
+        }
+    }
+}
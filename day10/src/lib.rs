@@ -0,0 +1,165 @@
+#[macro_use]
+extern crate utils;
+
+use utils::{parse_lines, Error, Grid, Point2, Rect, Solution, Vector2};
+
+#[derive(Clone)]
+pub struct Star {
+    pub position: Point2,
+    pub velocity: Vector2,
+}
+
+pub type Canvas = Grid<bool>;
+
+impl Star {
+    pub fn advance(&mut self) {
+        self.position += self.velocity;
+    }
+}
+
+line_parser! {
+    pub fn parse_star -> Star {
+        pattern: r"(?x)
+position = < \s* (?P<px>-?\d+) , \s* (?P<py>-?\d+) > \s*
+velocity = < \s* (?P<vx>-?\d+) , \s* (?P<vy>-?\d+) >",
+        expected: "a star like 'position=< 9,  1> velocity=< 0,  2>'",
+        |px: i64, py: i64, vx: i64, vy: i64| Star {
+            position: Point2::new(px, py),
+            velocity: Vector2::new(vx, vy),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Star>, Error> {
+    let stars = parse_lines(input, parse_star)?;
+    if stars.is_empty() {
+        return Err(Error::invalid("there are no stars"));
+    }
+    Ok(stars)
+}
+
+fn bounding_box(stars: &[Star]) -> Rect {
+    Rect::bounding(stars.iter().map(|star| star.position)).unwrap()
+}
+
+fn bounding_box_size(stars: &[Star]) -> (i64, i64) {
+    let bounds = bounding_box(stars);
+    (bounds.width(), bounds.height())
+}
+
+pub fn laydown(stars: &[Star]) -> Option<Canvas> {
+    let bounds = bounding_box(stars);
+    if bounds.width().saturating_mul(bounds.height()) > 10000 {
+        return None;
+    }
+
+    let mut canvas = Grid::with_bounds(bounds, false);
+    for star in stars {
+        canvas[star.position] = true;
+    }
+
+    Some(canvas)
+}
+
+pub fn render(canvas: &Canvas) -> String {
+    canvas
+        .map(|&with_star| if with_star { '#' } else { '.' })
+        .to_string()
+}
+
+pub fn advance_stars(stars: &mut [Star]) {
+    for star in stars {
+        star.advance();
+    }
+}
+
+pub fn might_contain_text(canvas: &Canvas) -> bool {
+    let mut longest_vertical_segment = 0;
+    for column in canvas.left()..canvas.right() {
+        let mut current_vertical_segment = 0;
+        for &with_star in canvas.column(column) {
+            if with_star {
+                current_vertical_segment += 1;
+            } else {
+                longest_vertical_segment = longest_vertical_segment.max(current_vertical_segment);
+                current_vertical_segment = 0;
+            }
+        }
+        longest_vertical_segment = longest_vertical_segment.max(current_vertical_segment);
+    }
+
+    longest_vertical_segment >= 8
+}
+
+pub fn wait_for_message(stars: &mut [Star]) -> Result<(Canvas, u32), Error> {
+    let mut time = 0;
+    let mut size = bounding_box_size(stars);
+    loop {
+        if let Some(canvas) = laydown(stars) {
+            if might_contain_text(&canvas) {
+                return Ok((canvas, time));
+            }
+        }
+        advance_stars(stars);
+        time += 1;
+
+        // Once the stars stop converging, they will only drift further apart.
+        let new_size = bounding_box_size(stars);
+        if new_size.0 >= size.0 && new_size.1 >= size.1 {
+            return Err(Error::no_solution("the stars never form a message"));
+        }
+        size = new_size;
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Star>;
+    type Answer1 = String;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Star>, Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, stars: &Vec<Star>) -> Result<String, Error> {
+        let (canvas, _) = wait_for_message(&mut stars.clone())?;
+        Ok(render(&canvas))
+    }
+
+    fn part2(&self, stars: &Vec<Star>) -> Result<u32, Error> {
+        let (_, time) = wait_for_message(&mut stars.clone())?;
+        Ok(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const MESSAGE: &str = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+
+    #[test]
+    fn part1_tests() {
+        let stars = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(MESSAGE, Day10.part1(&stars).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let stars = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(3, Day10.part2(&stars).unwrap());
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(feature = "parallel")]
+extern crate rayon;
+extern crate serde;
+extern crate utils;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::ops::RangeInclusive;
+#[cfg(not(feature = "parallel"))]
+use utils::iter_dep_product;
+use utils::{iter_product, Error, Grid, Point2, Rect, Solution};
+#[cfg(feature = "parallel")]
+use utils::{par_iter_dep_product, par_iter_product};
+
+pub fn power_level(x: usize, y: usize, serial_number: i32) -> i32 {
+    let rack_id = x as i32 + 10;
+    (((rack_id * y as i32 + serial_number) * rack_id) % 1000) / 100 - 5
+}
+
+// The top left corners of the squares of every size, as (size, (x, y)).
+#[cfg(not(feature = "parallel"))]
+fn squares(
+    grid_size: usize,
+    square_sizes: RangeInclusive<usize>,
+) -> impl Iterator<Item = (usize, (usize, usize))> {
+    iter_dep_product(square_sizes, move |square_size| {
+        let last_corner = grid_size + 1 - square_size;
+        iter_product(1..=last_corner, 1..=last_corner)
+    })
+}
+
+#[cfg(feature = "parallel")]
+fn squares(
+    grid_size: usize,
+    square_sizes: RangeInclusive<usize>,
+) -> impl ParallelIterator<Item = (usize, (usize, usize))> {
+    par_iter_dep_product(square_sizes, move |square_size| {
+        let last_corner = grid_size + 1 - square_size;
+        par_iter_product(1..=last_corner, 1..=last_corner)
+    })
+}
+
+pub fn optimize_power(
+    serial_number: i32,
+    grid_size: usize,
+    square_sizes: RangeInclusive<usize>,
+) -> Option<(usize, (usize, usize))> {
+    let power_grid = Grid::from_fn(Rect::new(1, 1, grid_size as i64, grid_size as i64), |p| {
+        power_level(p.x as usize, p.y as usize, serial_number)
+    });
+
+    let last_square_size = grid_size.min(*square_sizes.end());
+    squares(grid_size, *square_sizes.start()..=last_square_size)
+        // Ties go to the last square in order, even when searching in parallel.
+        .max_by_key(|&(square_size, (xc, yc))| {
+            let power = iter_product(xc..xc + square_size, yc..yc + square_size)
+                .map(|(x, y)| power_grid[Point2::new(x as i64, y as i64)])
+                .sum::<i32>();
+            (power, square_size, xc, yc)
+        })
+}
+
+pub struct Location {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Serialize for Location {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y).serialize(serializer)
+    }
+}
+
+pub struct Square {
+    pub x: usize,
+    pub y: usize,
+    pub size: usize,
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.size)
+    }
+}
+
+impl Serialize for Square {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y, self.size).serialize(serializer)
+    }
+}
+
+/// `serial_number` replaces the one in the input when set.
+#[derive(Debug)]
+pub struct Day11 {
+    pub serial_number: Option<i32>,
+    pub grid_size: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Day11 {
+        Day11 {
+            serial_number: None,
+            grid_size: 300,
+        }
+    }
+}
+
+impl Day11 {
+    fn optimize(
+        &self,
+        serial_number: i32,
+        square_sizes: RangeInclusive<usize>,
+    ) -> Result<Square, Error> {
+        let serial_number = self.serial_number.unwrap_or(serial_number);
+        let (size, (x, y)) = optimize_power(serial_number, self.grid_size, square_sizes)
+            .ok_or_else(|| Error::invalid("the grid is too small"))?;
+        Ok(Square { x, y, size })
+    }
+}
+
+impl Solution for Day11 {
+    type Input = i32;
+    type Answer1 = Location;
+    type Answer2 = Square;
+
+    const DEFAULT_INPUT: Option<&'static str> = Some("6042\n");
+
+    fn parse(input: &str) -> Result<i32, Error> {
+        input
+            .trim()
+            .parse()
+            .map_err(|e| Error::parse(1, 1, format!("invalid serial number: {}", e)))
+    }
+
+    fn part1(&self, &serial_number: &i32) -> Result<Location, Error> {
+        let Square { x, y, .. } = self.optimize(serial_number, 3..=3)?;
+        Ok(Location { x, y })
+    }
+
+    fn part2(&self, &serial_number: &i32) -> Result<Square, Error> {
+        self.optimize(serial_number, 1..=self.grid_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_level_tests() {
+        assert_eq!(-5, power_level(122, 79, 57));
+        assert_eq!(0, power_level(217, 196, 39));
+        assert_eq!(4, power_level(101, 153, 71));
+    }
+
+    #[test]
+    fn optimize_power_tests() {
+        assert_eq!(Some((3, (33, 45))), optimize_power(18, 300, 3..=3));
+        assert_eq!(Some((3, (21, 61))), optimize_power(42, 300, 3..=3));
+        assert_eq!(Some((16, (90, 269))), optimize_power(18, 300, 16..=16));
+        assert_eq!(Some((12, (232, 251))), optimize_power(42, 300, 12..=12));
+        assert_eq!(Some((1, (2, 2))), optimize_power(18, 2, 1..=3));
+        assert_eq!(None, optimize_power(18, 2, 3..=3));
+    }
+
+    #[test]
+    fn parse_tests() {
+        assert_eq!(18, Day11::parse("18\n").unwrap());
+        assert_eq!(-7, Day11::parse("-7").unwrap());
+        assert!(Day11::parse("18 42\n").is_err());
+    }
+}
@@ -0,0 +1,358 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+use utils::{find_cycle_by, Error, Solution};
+
+#[derive(Clone)]
+pub struct Slice<A> {
+    vec: Vec<A>,
+    begin: isize,
+}
+
+pub type Rules = [bool; 32];
+type SegmentType = usize;
+
+fn segment_type(segment: [bool; 5]) -> SegmentType {
+    let mut segment_type = 0;
+    for (i, &b) in segment.iter().enumerate() {
+        if b {
+            segment_type |= 1 << i;
+        }
+    }
+    segment_type
+}
+
+fn parse_pot(c: char, line: usize, column: usize) -> Result<bool, Error> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(Error::parse(
+            line,
+            column,
+            format!("expected '#' or '.', found '{}'", c),
+        )),
+    }
+}
+
+pub struct SlicePositionsIter<'a> {
+    slice: &'a Slice<bool>,
+    idx: isize,
+}
+
+impl<'a> Iterator for SlicePositionsIter<'a> {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.slice.end() {
+            if self.slice[self.idx] {
+                self.idx += 1;
+                return Some(self.idx - 1);
+            } else {
+                self.idx += 1;
+            }
+        }
+        None
+    }
+}
+
+impl<A> FromIterator<A> for Slice<A> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = A>,
+    {
+        Slice {
+            vec: iter.into_iter().collect(),
+            begin: 0,
+        }
+    }
+}
+
+impl<A> Slice<A>
+where
+    A: Copy + Default,
+{
+    pub fn new(begin: isize, end: isize) -> Slice<A> {
+        assert!(begin <= end);
+        Slice {
+            vec: vec![A::default(); (end - begin) as usize],
+            begin,
+        }
+    }
+}
+
+impl<A> Slice<A> {
+    pub fn begin(&self) -> isize {
+        self.begin
+    }
+
+    pub fn end(&self) -> isize {
+        self.begin + self.vec.len() as isize
+    }
+
+    pub fn shift(&mut self, delta: isize) {
+        self.begin += delta;
+    }
+}
+
+impl Slice<bool> {
+    pub fn active_positions(&self) -> SlicePositionsIter<'_> {
+        SlicePositionsIter {
+            slice: self,
+            idx: self.begin(),
+        }
+    }
+
+    fn segment_around(&self, index: isize) -> SegmentType {
+        segment_type([
+            self[index - 2],
+            self[index - 1],
+            self[index],
+            self[index + 1],
+            self[index + 2],
+        ])
+    }
+}
+
+impl Index<isize> for Slice<bool> {
+    type Output = bool;
+
+    fn index(&self, index: isize) -> &Self::Output {
+        if index >= self.begin() && index < self.end() {
+            &self.vec[(index - self.begin) as usize]
+        } else {
+            &false
+        }
+    }
+}
+
+impl IndexMut<isize> for Slice<bool> {
+    fn index_mut(&mut self, index: isize) -> &mut Self::Output {
+        assert!(index >= self.begin() && index < self.end());
+        &mut self.vec[(index - self.begin) as usize]
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Slice<bool>, Rules), Error> {
+    let mut lines = input.lines();
+
+    let prefix = "initial state: ";
+    let first_line = lines.next().unwrap_or("").trim();
+    if !first_line.starts_with(prefix) {
+        return Err(Error::parse(
+            1,
+            1,
+            "expected the initial state like 'initial state: #..#.#'",
+        ));
+    }
+    let initial_slice = first_line[prefix.len()..]
+        .chars()
+        .enumerate()
+        .map(|(i, c)| parse_pot(c, 1, prefix.len() + i + 1))
+        .collect::<Result<Slice<bool>, Error>>()?;
+
+    let mut rules = [false; 32];
+    for (i, line) in lines.enumerate() {
+        let line_number = i + 2;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let chars = line.chars().collect::<Vec<_>>();
+        if chars.len() != 10 || chars[5..9] != [' ', '=', '>', ' '] {
+            return Err(Error::parse(
+                line_number,
+                1,
+                "expected a rule like '...## => #'",
+            ));
+        }
+        let mut segment = [false; 5];
+        for (j, &c) in chars[..5].iter().enumerate() {
+            segment[j] = parse_pot(c, line_number, j + 1)?;
+        }
+        rules[segment_type(segment)] = parse_pot(chars[9], line_number, 10)?;
+    }
+
+    if rules[0] {
+        return Err(Error::invalid(
+            "the rule '..... => #' would grow infinitely many plants",
+        ));
+    }
+
+    Ok((initial_slice, rules))
+}
+
+impl fmt::Display for Slice<bool> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.begin())?;
+        for i in self.begin()..self.end() {
+            write!(f, "{}", if self[i] { '#' } else { '.' })?;
+        }
+        Ok(())
+    }
+}
+
+fn next_generation(slice: &Slice<bool>, rules: &Rules) -> Slice<bool> {
+    let new_begin = match slice.active_positions().min() {
+        Some(first_plant) => first_plant - 2,
+        None => return slice.clone(),
+    };
+    let new_end = slice.active_positions().max().unwrap() + 3;
+    let mut new_slice = Slice::new(new_begin, new_end);
+    for p in new_slice.begin()..new_slice.end() {
+        new_slice[p] = rules[slice.segment_around(p)];
+    }
+    new_slice
+}
+
+// The plants grow the same way wherever they are, so the generations are
+// compared by the positions of the plants relative to the first one.
+fn shape(slice: &Slice<bool>) -> (Vec<isize>, isize) {
+    let first_plant = slice.active_positions().next().unwrap_or(0);
+    let shape = slice.active_positions().map(|p| p - first_plant).collect();
+    (shape, first_plant)
+}
+
+pub fn simulate(slice: Slice<bool>, rules: &Rules, iterations: u64) -> Slice<bool> {
+    // The generations are kept as the search for a cycle goes past them, so
+    // that the one looking like the last is not simulated again.
+    let mut generations = vec![slice.clone()];
+    let step = |slice: &Slice<bool>| {
+        if generations.len() as u64 > iterations {
+            return None;
+        }
+        let next = next_generation(slice, rules);
+        generations.push(next.clone());
+        Some(next)
+    };
+    let (generation, drift) = match find_cycle_by(slice, step, shape) {
+        Some(cycle) => {
+            let (generation, periods) = cycle.reduce(iterations);
+            (generation, periods as isize * cycle.drift)
+        }
+        None => (generations.len() - 1, 0),
+    };
+
+    let mut slice = generations.swap_remove(generation);
+    slice.shift(drift);
+    slice
+}
+
+#[derive(Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Slice<bool>, Rules);
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<(Slice<bool>, Rules), Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (initial_state, rules): &(Slice<bool>, Rules)) -> Result<isize, Error> {
+        let final_state = simulate(initial_state.clone(), rules, 20);
+        Ok(final_state.active_positions().sum())
+    }
+
+    fn part2(&self, (initial_state, rules): &(Slice<bool>, Rules)) -> Result<isize, Error> {
+        let final_state = simulate(initial_state.clone(), rules, 50_000_000_000);
+        Ok(final_state.active_positions().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    // Grows the plants one generation after another, the plants kept as a set
+    // of positions and the rules as a set of the patterns that grow a plant.
+    fn naive_simulate(input: &str, iterations: u64) -> Vec<isize> {
+        let mut lines = input.lines();
+        let initial_state = lines.next().unwrap().trim_start_matches("initial state: ");
+        let mut plants = initial_state
+            .chars()
+            .enumerate()
+            .filter(|&(_, c)| c == '#')
+            .map(|(i, _)| i as isize)
+            .collect::<BTreeSet<_>>();
+        let growing = lines
+            .filter(|line| line.ends_with('#'))
+            .map(|line| line[..5].to_owned())
+            .collect::<HashSet<_>>();
+
+        for _ in 0..iterations {
+            let (first, last) = match (plants.iter().next(), plants.iter().next_back()) {
+                (Some(&first), Some(&last)) => (first, last),
+                _ => break,
+            };
+            plants = (first - 2..=last + 2)
+                .filter(|&p| {
+                    let pattern = (p - 2..=p + 2)
+                        .map(|q| if plants.contains(&q) { '#' } else { '.' })
+                        .collect::<String>();
+                    growing.contains(&pattern)
+                })
+                .collect();
+        }
+        plants.into_iter().collect()
+    }
+
+    fn input(initial_state: &str, results: &[bool]) -> String {
+        let mut input = format!("initial state: {}\n\n", initial_state);
+        for (i, &result) in results.iter().enumerate() {
+            // The rule for i + 1, as '.....' must not grow a plant.
+            let pattern = (0..5)
+                .map(|bit| {
+                    if (i + 1) & (1 << (4 - bit)) != 0 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            let result = if result { '#' } else { '.' };
+            input.push_str(&format!("{} => {}\n", pattern, result));
+        }
+        input
+    }
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    // Changes shape in a cycle of four generations, moving eight pots further
+    // in each cycle.
+    const OSCILLATOR: &str = include_str!("../fixtures/oscillator.txt");
+
+    #[test]
+    fn part1_tests() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(325, Day12.part1(&input).unwrap());
+        let input = Day12::parse(OSCILLATOR).unwrap();
+        assert_eq!(199, Day12.part1(&input).unwrap());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(999_999_999_374, Day12.part2(&input).unwrap());
+        let input = Day12::parse(OSCILLATOR).unwrap();
+        assert_eq!(499_999_999_999, Day12.part2(&input).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn simulate_proptests(
+            initial_state in "[#.]{1,16}",
+            results in prop::collection::vec(any::<bool>(), 31),
+            iterations in 0..100u64,
+        ) {
+            let input = input(&initial_state, &results);
+            let (initial_slice, rules) = parse_input(&input).unwrap();
+            let final_slice = simulate(initial_slice, &rules, iterations);
+            prop_assert_eq!(
+                naive_simulate(&input, iterations),
+                final_slice.active_positions().collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use utils::{Error, Grid, Point2, Solution, Vector2};
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy)]
+pub enum IntersectionChoice {
+    GoLeft,
+    GoStraight,
+    GoRight,
+}
+
+impl IntersectionChoice {
+    fn next(self) -> Self {
+        match self {
+            IntersectionChoice::GoLeft => IntersectionChoice::GoStraight,
+            IntersectionChoice::GoStraight => IntersectionChoice::GoRight,
+            IntersectionChoice::GoRight => IntersectionChoice::GoLeft,
+        }
+    }
+
+    fn turn(self, dir: Vector2) -> Vector2 {
+        match self {
+            IntersectionChoice::GoLeft => dir.turn_left(),
+            IntersectionChoice::GoStraight => dir,
+            IntersectionChoice::GoRight => dir.turn_right(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Cart {
+    pub pos: Point2,
+    pub dir: Vector2,
+    pub next_intersection: IntersectionChoice,
+}
+
+impl Cart {
+    pub fn update(&mut self, map: &Map) -> Result<(), Error> {
+        self.pos += self.dir;
+        match map.get(self.pos) {
+            Some(Tile::StraightPath) => (),
+            Some(Tile::Curve(curve)) => {
+                self.dir = curve.bend(self.dir);
+            }
+            Some(Tile::Intersection) => {
+                self.dir = self.next_intersection.turn(self.dir);
+                self.next_intersection = self.next_intersection.next();
+            }
+            Some(Tile::Empty) | None => {
+                return Err(Error::invalid(format!(
+                    "a cart has gone off the tracks at {}",
+                    self.pos
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+struct CartInSimulation(Cart);
+
+impl PartialEq for CartInSimulation {
+    fn eq(&self, other: &CartInSimulation) -> bool {
+        self.0.pos.eq(&other.0.pos)
+    }
+}
+
+impl Eq for CartInSimulation {}
+
+impl PartialOrd for CartInSimulation {
+    fn partial_cmp(&self, other: &CartInSimulation) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CartInSimulation {
+    fn cmp(&self, other: &CartInSimulation) -> Ordering {
+        let y_cmp = self.0.pos.y.cmp(&other.0.pos.y).reverse();
+        let x_cmp = self.0.pos.x.cmp(&other.0.pos.x).reverse();
+        y_cmp.then(x_cmp)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    Slash,
+    Backslash,
+}
+
+impl Curve {
+    fn bend(self, dir: Vector2) -> Vector2 {
+        match self {
+            Curve::Slash => Vector2::new(-dir.y, -dir.x),
+            Curve::Backslash => Vector2::new(dir.y, dir.x),
+        }
+    }
+}
+
+pub enum Tile {
+    StraightPath,
+    Curve(Curve),
+    Intersection,
+    Empty,
+}
+
+pub type Map = Grid<Tile>;
+
+pub fn parse_input(input: &str) -> Result<(Map, Vec<Cart>), Error> {
+    let mut carts = Vec::new();
+
+    let map = Grid::parse(input, |pos, c| {
+        Ok(match c {
+            '|' | '-' => Tile::StraightPath,
+            '/' => Tile::Curve(Curve::Slash),
+            '\\' => Tile::Curve(Curve::Backslash),
+            '+' => Tile::Intersection,
+            ' ' => Tile::Empty,
+            '^' | 'v' | '<' | '>' => {
+                carts.push(Cart {
+                    pos,
+                    dir: match c {
+                        '^' => Vector2::UP,
+                        'v' => Vector2::DOWN,
+                        '<' => Vector2::LEFT,
+                        '>' => Vector2::RIGHT,
+                        _ => unreachable!(),
+                    },
+                    next_intersection: IntersectionChoice::GoLeft,
+                });
+                Tile::StraightPath
+            }
+            _ => return Err(format!("unexpected character '{}' in the map", c)),
+        })
+    })?;
+
+    Ok((map, carts))
+}
+
+pub fn simulate_until_first_crash(map: &Map, carts: &[Cart]) -> Result<Point2, Error> {
+    if carts.len() < 2 {
+        return Err(Error::no_solution("there are not enough carts to crash"));
+    }
+
+    let mut queue = carts
+        .iter()
+        .map(|&cart| CartInSimulation(cart))
+        .collect::<BinaryHeap<CartInSimulation>>();
+    let mut next_queue = BinaryHeap::new();
+    let mut occupied = carts
+        .iter()
+        .map(|cart| cart.pos)
+        .collect::<HashSet<Point2>>();
+
+    loop {
+        while let Some(CartInSimulation(mut cart)) = queue.pop() {
+            occupied.remove(&cart.pos);
+            cart.update(map)?;
+            if !occupied.insert(cart.pos) {
+                return Ok(cart.pos);
+            }
+            next_queue.push(CartInSimulation(cart));
+        }
+        std::mem::swap(&mut queue, &mut next_queue);
+    }
+}
+
+fn remove_from_heap(
+    heap: BinaryHeap<CartInSimulation>,
+    pos: Point2,
+) -> BinaryHeap<CartInSimulation> {
+    heap.into_iter()
+        .filter(|CartInSimulation(cart2)| pos != cart2.pos)
+        .collect()
+}
+
+pub fn simulate_until_last_cart(map: &Map, carts: &[Cart]) -> Result<Point2, Error> {
+    let mut queue = carts
+        .iter()
+        .map(|&cart| CartInSimulation(cart))
+        .collect::<BinaryHeap<CartInSimulation>>();
+    let mut next_queue = BinaryHeap::new();
+    let mut occupied = carts
+        .iter()
+        .map(|cart| cart.pos)
+        .collect::<HashSet<Point2>>();
+
+    loop {
+        if queue.is_empty() {
+            return Err(Error::no_solution("all the carts crash"));
+        }
+        if queue.len() == 1 {
+            return Ok(queue.pop().unwrap().0.pos);
+        }
+        while let Some(CartInSimulation(mut cart)) = queue.pop() {
+            occupied.remove(&cart.pos);
+            cart.update(map)?;
+            if !occupied.insert(cart.pos) {
+                occupied.remove(&cart.pos);
+                queue = remove_from_heap(queue, cart.pos);
+                next_queue = remove_from_heap(next_queue, cart.pos);
+            } else {
+                next_queue.push(CartInSimulation(cart));
+            }
+        }
+        std::mem::swap(&mut queue, &mut next_queue);
+    }
+}
+
+pub fn wait_on_input() {
+    let mut dummy_buffer = String::new();
+    std::io::stdin().read_line(&mut dummy_buffer).unwrap();
+    print!("{control}[2J", control = 27 as char);
+}
+
+fn bold_red(text: &str) -> String {
+    format!("{control}[1;31m{}{control}[0m", text, control = 27 as char)
+}
+
+pub fn print_state(map: &Map, carts: &[Cart]) {
+    for (y, row) in (map.top()..).zip(map.rows()) {
+        for (x, tile) in (map.left()..).zip(row) {
+            let s = if let Some(cart) = carts.iter().find(|cart| cart.pos == Point2::new(x, y)) {
+                match cart.dir {
+                    Vector2::UP => bold_red("^"),
+                    Vector2::DOWN => bold_red("v"),
+                    Vector2::LEFT => bold_red("<"),
+                    Vector2::RIGHT => bold_red(">"),
+                    _ => panic!("Unexpected direction of cart!"),
+                }
+            } else {
+                match tile {
+                    Tile::StraightPath => ".".to_owned(),
+                    Tile::Curve(Curve::Slash) => "/".to_owned(),
+                    Tile::Curve(Curve::Backslash) => "\\".to_owned(),
+                    Tile::Intersection => "+".to_owned(),
+                    Tile::Empty => " ".to_owned(),
+                }
+            };
+            print!("{}", s);
+        }
+        println!();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Map, Vec<Cart>);
+    type Answer1 = Point2;
+    type Answer2 = Point2;
+
+    fn parse(input: &str) -> Result<(Map, Vec<Cart>), Error> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (map, carts): &(Map, Vec<Cart>)) -> Result<Point2, Error> {
+        simulate_until_first_crash(map, carts)
+    }
+
+    fn part2(&self, (map, carts): &(Map, Vec<Cart>)) -> Result<Point2, Error> {
+        simulate_until_last_cart(map, carts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("../fixtures/example1.txt");
+    const EXAMPLE2: &str = include_str!("../fixtures/example2.txt");
+
+    #[test]
+    fn part1_tests() {
+        let input = Day13::parse(EXAMPLE1).unwrap();
+        assert_eq!("7,3", Day13.part1(&input).unwrap().to_string());
+    }
+
+    #[test]
+    fn part2_tests() {
+        let input = Day13::parse(EXAMPLE2).unwrap();
+        assert_eq!("6,4", Day13.part2(&input).unwrap().to_string());
+    }
+}
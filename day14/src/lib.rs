@@ -0,0 +1,200 @@
+use aho_corasick::{AcAutomaton, Automaton};
+use std::fmt::Write;
+use std::io;
+use std::io::Read;
+use utils::{Error, Solution};
+
+pub struct KitchenState {
+    recipes: Vec<u8>,
+    first_cook: usize,
+    second_cook: usize,
+}
+
+impl Default for KitchenState {
+    fn default() -> KitchenState {
+        Self::new()
+    }
+}
+
+impl KitchenState {
+    pub fn new() -> KitchenState {
+        Self::with_capacity(1000)
+    }
+
+    pub fn with_capacity(capacity: usize) -> KitchenState {
+        let mut recipes = Vec::with_capacity(capacity);
+        recipes.push(3);
+        recipes.push(7);
+        KitchenState {
+            recipes,
+            first_cook: 0,
+            second_cook: 1,
+        }
+    }
+
+    pub fn cook(&mut self) {
+        let first_score = self.recipes[self.first_cook];
+        let second_score = self.recipes[self.second_cook];
+        let score_sum = first_score + second_score;
+        let tens_digit = score_sum / 10;
+        let ones_digit = score_sum % 10;
+        if tens_digit > 0 {
+            self.recipes.push(tens_digit);
+        }
+        self.recipes.push(ones_digit);
+        self.first_cook = (self.first_cook + first_score as usize + 1) % self.recipes.len();
+        self.second_cook = (self.second_cook + second_score as usize + 1) % self.recipes.len();
+    }
+
+    pub fn into_reader(self) -> KitchenReader {
+        KitchenReader {
+            kitchen_state: self,
+            produced: 0,
+        }
+    }
+}
+
+pub struct KitchenReader {
+    kitchen_state: KitchenState,
+    produced: usize,
+}
+
+impl Read for KitchenReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len();
+        while self.kitchen_state.recipes.len() < self.produced + n {
+            self.kitchen_state.cook();
+        }
+        buf.copy_from_slice(&self.kitchen_state.recipes[self.produced..self.produced + n]);
+        self.produced += n;
+        Ok(n)
+    }
+}
+
+pub fn find_10_recipes_at_offset(offset: usize) -> String {
+    let mut kitchen_state = KitchenState::with_capacity(offset + 10 + 1);
+    while kitchen_state.recipes.len() < offset + 10 {
+        kitchen_state.cook();
+    }
+    let mut score_string = String::with_capacity(10);
+    for score in kitchen_state.recipes[offset..offset + 10].iter() {
+        write!(&mut score_string, "{}", score).unwrap();
+    }
+    score_string
+}
+
+pub fn puzzle_input_to_score_sequence(input: usize) -> Vec<u8> {
+    format!("{}", input)
+        .chars()
+        .map(|c| {
+            let mut string = String::with_capacity(1);
+            string.push(c);
+            string.parse().unwrap()
+        })
+        .collect()
+}
+
+pub fn find_index_of_recipe_scores<P>(score_sequence: P) -> Option<usize>
+where
+    P: AsRef<[u8]>,
+{
+    let patterns = [score_sequence];
+    let automaton = AcAutomaton::new(&patterns);
+    automaton
+        .stream_find(KitchenState::new().into_reader())
+        .next()
+        .map(|m| m.unwrap().start)
+}
+
+/// `puzzle_input` replaces the number of recipes in the input when set.
+#[derive(Debug, Default)]
+pub struct Day14 {
+    pub puzzle_input: Option<usize>,
+}
+
+impl Solution for Day14 {
+    /// The digits of the puzzle input, whose leading zeros matter in part 2.
+    type Input = Vec<u8>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    const DEFAULT_INPUT: Option<&'static str> = Some("293801\n");
+
+    fn parse(input: &str) -> Result<Vec<u8>, Error> {
+        let input = input.trim_end();
+        if input.is_empty() {
+            return Err(Error::parse(1, 1, "expected the number of recipes"));
+        }
+        input
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(Error::parse(
+                    1,
+                    i + 1,
+                    format!("expected a digit, found {:?}", c),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(&self, digits: &Vec<u8>) -> Result<String, Error> {
+        let offset = match self.puzzle_input {
+            Some(offset) => offset,
+            None => digits
+                .iter()
+                .try_fold(0usize, |n, &digit| {
+                    n.checked_mul(10)?.checked_add(digit as usize)
+                })
+                .ok_or_else(|| Error::invalid("the number of recipes is too large"))?,
+        };
+        Ok(find_10_recipes_at_offset(offset))
+    }
+
+    fn part2(&self, digits: &Vec<u8>) -> Result<usize, Error> {
+        let score_sequence = match self.puzzle_input {
+            Some(puzzle_input) => puzzle_input_to_score_sequence(puzzle_input),
+            None => digits.clone(),
+        };
+        find_index_of_recipe_scores(score_sequence)
+            .ok_or_else(|| Error::no_solution("the score sequence never occurs"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_tests() {
+        assert_eq!("5158916779", find_10_recipes_at_offset(9));
+        assert_eq!("0124515891", find_10_recipes_at_offset(5));
+        assert_eq!("9251071085", find_10_recipes_at_offset(18));
+        assert_eq!("5941429882", find_10_recipes_at_offset(2018));
+    }
+
+    #[test]
+    fn part2_tests() {
+        assert_eq!(Some(9), find_index_of_recipe_scores([5, 1, 5, 8, 9]));
+        assert_eq!(Some(5), find_index_of_recipe_scores([0, 1, 2, 4, 5]));
+        assert_eq!(Some(18), find_index_of_recipe_scores([9, 2, 5, 1, 0]));
+        assert_eq!(Some(2018), find_index_of_recipe_scores([5, 9, 4, 1, 4]));
+        assert_eq!(vec![5, 1, 5, 8, 9], puzzle_input_to_score_sequence(51589));
+    }
+
+    #[test]
+    fn parse_tests() {
+        let digits = Day14::parse("01245\r\n").unwrap();
+        assert_eq!(vec![0, 1, 2, 4, 5], digits);
+        assert_eq!(
+            Ok(5),
+            Day14::default().part2(&digits).map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            "line 1, column 3: expected a digit, found 'x'",
+            Day14::parse("12x").unwrap_err().to_string()
+        );
+        assert!(Day14::parse("\n").is_err());
+    }
+}
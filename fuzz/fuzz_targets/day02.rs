@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(input) = day02::Day02::parse(input) {
+        let day = day02::Day02::default();
+        let _ = day.part1(&input);
+        let _ = day.part2(&input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::Solution;
+
+fuzz_target!(|input: &str| {
+    if let Ok(input) = day07::Day07::parse(input) {
+        let day = day07::Day07::default();
+        let _ = day.part1(&input);
+        let _ = day.part2(&input);
+    }
+});
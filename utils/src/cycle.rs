@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// A sequence of states which, from step `start` on, repeats every `period`
+/// steps, moved by `drift` on each repetition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle<D> {
+    pub start: usize,
+    pub period: usize,
+    pub drift: D,
+}
+
+impl<D> Cycle<D> {
+    /// The step before the end of the first period that looks the same as step
+    /// `n`, and how many periods, and so drifts, lie between the two.
+    pub fn reduce(&self, n: u64) -> (usize, u64) {
+        let start = self.start as u64;
+        if n < start {
+            return (n as usize, 0);
+        }
+        let period = self.period as u64;
+        let offset = n - start;
+        (self.start + (offset % period) as usize, offset / period)
+    }
+}
+
+/// Finds the first state that repeats in the sequence starting at `initial`,
+/// remembering every state on the way. The sequence ends when `step` returns
+/// `None`.
+pub fn find_cycle<S, F>(initial: S, step: F) -> Option<Cycle<()>>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> Option<S>,
+{
+    let cycle = find_cycle_by(initial, step, |state| (state.clone(), 0))?;
+    Some(Cycle {
+        start: cycle.start,
+        period: cycle.period,
+        drift: (),
+    })
+}
+
+/// Like `find_cycle`, but compares the states by a key from `normalise`, which
+/// also returns where the state lies, e.g. to find patterns that repeat in a
+/// different place.
+pub fn find_cycle_by<S, K, D, F, N>(initial: S, mut step: F, mut normalise: N) -> Option<Cycle<D>>
+where
+    K: Eq + Hash,
+    D: Copy + Sub<Output = D>,
+    F: FnMut(&S) -> Option<S>,
+    N: FnMut(&S) -> (K, D),
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut index = 0;
+    loop {
+        let (key, offset) = normalise(&state);
+        if let Some(&(start, start_offset)) = seen.get(&key) {
+            return Some(Cycle {
+                start,
+                period: index - start,
+                drift: offset - start_offset,
+            });
+        }
+        seen.insert(key, (index, offset));
+        state = step(&state)?;
+        index += 1;
+    }
+}
+
+/// Brent's algorithm, which finds the same cycle as `find_cycle_by` in
+/// constant memory, at the cost of computing some of the steps again.
+pub fn brent<S, K, D, F, N>(initial: S, mut step: F, mut normalise: N) -> Option<Cycle<D>>
+where
+    S: Clone,
+    K: Eq,
+    D: Sub<Output = D>,
+    F: FnMut(&S) -> Option<S>,
+    N: FnMut(&S) -> (K, D),
+{
+    // Find the period by looking for the tortoise from ever further away.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = normalise(&initial).0;
+    let mut hare = step(&initial)?;
+    loop {
+        let key = normalise(&hare).0;
+        if key == tortoise {
+            break;
+        }
+        if power == period {
+            tortoise = key;
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare)?;
+        period += 1;
+    }
+
+    // Then walk two states a period apart until they meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    loop {
+        let (tortoise_key, tortoise_offset) = normalise(&tortoise);
+        let (hare_key, hare_offset) = normalise(&hare);
+        if tortoise_key == hare_key {
+            return Some(Cycle {
+                start,
+                period,
+                drift: hare_offset - tortoise_offset,
+            });
+        }
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_plus_one(x: &u32) -> Option<u32> {
+        Some((x * x + 1) % 255)
+    }
+
+    #[test]
+    fn find_cycle_tests() {
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        let cycle = find_cycle(3, square_plus_one).unwrap();
+        assert_eq!((2, 6), (cycle.start, cycle.period));
+        assert_eq!((4, 0), cycle.reduce(4));
+        assert_eq!((3, 4), cycle.reduce(27));
+        assert_eq!(
+            None,
+            find_cycle(0, |&x: &u32| if x < 10 { Some(x + 1) } else { None })
+        );
+    }
+
+    #[test]
+    fn drift_tests() {
+        // A pattern that blinks between two shapes while moving two to the
+        // right every other step.
+        let step = |&(blink, position): &(bool, i64)| {
+            Some((!blink, if blink { position + 2 } else { position }))
+        };
+        let normalise = |&(blink, position): &(bool, i64)| (blink, position);
+        let expected = Cycle {
+            start: 0,
+            period: 2,
+            drift: 2,
+        };
+        assert_eq!(Some(expected), find_cycle_by((false, 5), step, normalise));
+        assert_eq!(Some(expected), brent((false, 5), step, normalise));
+    }
+
+    #[test]
+    fn brent_tests() {
+        let split = |x: &u32| (*x, 0);
+        for initial in 0..255 {
+            assert_eq!(
+                find_cycle_by(initial, square_plus_one, split),
+                brent(initial, square_plus_one, split)
+            );
+        }
+        assert_eq!(
+            None,
+            brent(0, |&x: &u32| if x < 10 { Some(x + 1) } else { None }, split)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input is malformed. Lines and columns are numbered from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The puzzle input is well-formed, but describes an impossible situation.
+    Invalid(String),
+    /// The puzzle has no answer for the given input.
+    NoSolution(String),
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn parse<M: Into<String>>(line: usize, column: usize, message: M) -> Error {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid<M: Into<String>>(message: M) -> Error {
+        Error::Invalid(message.into())
+    }
+
+    pub fn no_solution<M: Into<String>>(message: M) -> Error {
+        Error::NoSolution(message.into())
+    }
+
+    /// Moves a parse error reported for a single line to the given line of the whole input.
+    pub fn at_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse {
+                column, message, ..
+            } => Error::Parse {
+                line,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Invalid(message) => write!(f, "invalid input: {}", message),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
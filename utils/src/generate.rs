@@ -0,0 +1,356 @@
+//! Random puzzle inputs for the days, to test the solutions on inputs larger
+//! or stranger than the puzzle ones. The same seed always gives the same
+//! input.
+
+use std::fmt::Write;
+
+/// A small xorshift generator, so that the generated inputs are the same on
+/// every run and platform and the timings stay comparable.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// The input generated for the day from the seed, if the day has a generator.
+pub fn generate(day: u32, seed: u64) -> Option<String> {
+    let generator: fn(&mut Rng) -> String = match day {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        10 => day10,
+        12 => day12,
+        13 => day13,
+        _ => return None,
+    };
+    Some(generator(&mut Rng::new(seed)))
+}
+
+pub fn day01(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..1_000 {
+        let change = rng.range(-20, 20);
+        writeln!(input, "{:+}", change).unwrap();
+    }
+    writeln!(input, "+1").unwrap();
+    input
+}
+
+fn random_box_id(rng: &mut Rng) -> Vec<u8> {
+    (0..26).map(|_| b'a' + rng.below(26) as u8).collect()
+}
+
+fn differences(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).filter(|(a, b)| a != b).count()
+}
+
+// The ids differ in at least two positions, except for the one pair that
+// differs in exactly one.
+pub fn day02(rng: &mut Rng) -> String {
+    let mut ids: Vec<Vec<u8>> = Vec::with_capacity(501);
+    while ids.len() < 500 {
+        let id = random_box_id(rng);
+        if ids.iter().all(|other| differences(&id, other) > 1) {
+            ids.push(id);
+        }
+    }
+    let twin = loop {
+        let mut twin = ids[rng.below(500) as usize].clone();
+        let position = rng.below(26) as usize;
+        twin[position] = b'a' + (twin[position] - b'a' + rng.range(1, 25) as u8) % 26;
+        if ids.iter().filter(|id| differences(id, &twin) < 2).count() == 1 {
+            break twin;
+        }
+    };
+    ids.insert(rng.below(500) as usize, twin);
+
+    let mut input = String::new();
+    for id in ids {
+        writeln!(input, "{}", String::from_utf8(id).unwrap()).unwrap();
+    }
+    input
+}
+
+pub fn day03(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for id in 1..=1_000 {
+        writeln!(
+            input,
+            "#{} @ {},{}: {}x{}",
+            id,
+            rng.below(975),
+            rng.below(975),
+            rng.range(5, 25),
+            rng.range(5, 25)
+        )
+        .unwrap();
+    }
+    input
+}
+
+// The guards fall asleep and wake up in turns, but the records are shuffled
+// like in the puzzle input.
+pub fn day04(rng: &mut Rng) -> String {
+    let mut records = Vec::new();
+    for month in 1..=12 {
+        for day in 1..=28 {
+            let date = format!("1518-{:02}-{:02}", month, day);
+            records.push(format!(
+                "[{} 00:00] Guard #{} begins shift",
+                date,
+                rng.range(1, 20) * 97
+            ));
+            let mut minute = 0;
+            while minute < 50 {
+                let asleep = rng.range(minute + 1, 50);
+                let awake = rng.range(asleep + 1, 59);
+                records.push(format!("[{} 00:{:02}] falls asleep", date, asleep));
+                records.push(format!("[{} 00:{:02}] wakes up", date, awake));
+                minute = awake;
+            }
+        }
+    }
+    rng.shuffle(&mut records);
+    records
+        .iter()
+        .map(|record| format!("{}\n", record))
+        .collect()
+}
+
+pub fn day05(rng: &mut Rng) -> String {
+    (0..50_000)
+        .map(|_| {
+            let unit = (b'a' + rng.below(10) as u8) as char;
+            if rng.chance(50) {
+                unit.to_ascii_uppercase()
+            } else {
+                unit
+            }
+        })
+        .collect()
+}
+
+pub fn day06(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..50 {
+        writeln!(input, "{}, {}", rng.range(50, 350), rng.range(50, 350)).unwrap();
+    }
+    input
+}
+
+pub fn day07(rng: &mut Rng) -> String {
+    let mut steps = (b'A'..=b'Z').map(|c| c as char).collect::<Vec<_>>();
+    rng.shuffle(&mut steps);
+
+    let mut input = String::new();
+    for (i, before) in steps.iter().enumerate() {
+        for after in &steps[i + 1..] {
+            if rng.chance(15) {
+                writeln!(
+                    input,
+                    "Step {} must be finished before step {} can begin.",
+                    before, after
+                )
+                .unwrap();
+            }
+        }
+    }
+    input
+}
+
+// The metadata of the nodes with children refer to one of them.
+fn write_node(rng: &mut Rng, depth: u32, output: &mut Vec<String>) {
+    let n_children = if depth == 0 { 0 } else { rng.range(1, 3) };
+    let n_metadata = rng.range(1, 3);
+    output.push(n_children.to_string());
+    output.push(n_metadata.to_string());
+    for _ in 0..n_children {
+        write_node(rng, depth - 1, output);
+    }
+    for _ in 0..n_metadata {
+        let entry = if n_children == 0 {
+            rng.range(1, 9)
+        } else {
+            rng.range(1, n_children)
+        };
+        output.push(entry.to_string());
+    }
+}
+
+pub fn day08(rng: &mut Rng) -> String {
+    let mut numbers = Vec::new();
+    write_node(rng, 8, &mut numbers);
+    numbers.join(" ")
+}
+
+// The stars meet in a word written with letters that are 6 columns wide and
+// 10 lines tall, like the ones in the puzzle.
+fn letter_pixels(letter: char) -> Vec<(i64, i64)> {
+    let mut pixels = Vec::new();
+    for y in 0..10 {
+        for x in 0..6 {
+            let on = match letter {
+                'H' => x == 0 || x == 5 || y == 4,
+                'I' => x == 2 || y == 0 || y == 9,
+                'L' => x == 0 || y == 9,
+                'T' => x == 2 || y == 0,
+                _ => false,
+            };
+            if on {
+                pixels.push((x, y));
+            }
+        }
+    }
+    pixels
+}
+
+pub fn day10(rng: &mut Rng) -> String {
+    let time = rng.range(5_000, 15_000);
+    let word = (0..8)
+        .map(|_| b"HILT"[rng.below(4) as usize] as char)
+        .collect::<String>();
+    let mut input = String::new();
+    for (i, letter) in word.chars().enumerate() {
+        for (x, y) in letter_pixels(letter) {
+            let x = x + 8 * i as i64;
+            let (vx, vy) = (rng.range(-5, 5), rng.range(-5, 5));
+            writeln!(
+                input,
+                "position=<{}, {}> velocity=<{}, {}>",
+                x - time * vx,
+                y - time * vy,
+                vx,
+                vy
+            )
+            .unwrap();
+        }
+    }
+    input
+}
+
+// The rules of the published example, under which random patterns soon settle
+// into a steady drift, so that the second part ends.
+const DAY12_RULES: &str = "\
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
+";
+
+pub fn day12(rng: &mut Rng) -> String {
+    let initial_state = (0..200)
+        .map(|_| if rng.chance(50) { '#' } else { '.' })
+        .collect::<String>();
+    format!("initial state: {}\n\n{}", initial_state, DAY12_RULES)
+}
+
+pub fn day13(rng: &mut Rng) -> String {
+    let (width, height) = (150, 150);
+    let mut map = vec![vec![' '; width]; height];
+    for (y, row) in map.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            let (on_left, on_right) = (x == 0, x == width - 1);
+            let (on_top, on_bottom) = (y == 0, y == height - 1);
+            *tile = match (on_left || on_right, on_top || on_bottom) {
+                (true, true) if on_left == on_top => '/',
+                (true, true) => '\\',
+                (true, false) => '|',
+                (false, true) => '-',
+                (false, false) => ' ',
+            };
+        }
+    }
+
+    // Every crash takes out one cart going each way round the loop, so one
+    // more counterclockwise cart is left standing at the end.
+    let n_clockwise = 8;
+    let mut placed = 0;
+    while placed < 2 * n_clockwise + 1 {
+        let x = rng.range(1, width as i64 - 2) as usize;
+        let y = if rng.chance(50) { 0 } else { height - 1 };
+        if map[y][x] != '-' {
+            continue;
+        }
+        let clockwise = placed < n_clockwise;
+        map[y][x] = match (y == 0, clockwise) {
+            (true, true) | (false, false) => '>',
+            (true, false) | (false, true) => '<',
+        };
+        placed += 1;
+    }
+
+    let mut input = String::new();
+    for row in map {
+        writeln!(input, "{}", row.into_iter().collect::<String>()).unwrap();
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_tests() {
+        assert_eq!(generate(7, 42), generate(7, 42));
+        assert_ne!(generate(7, 42), generate(7, 43));
+        assert_eq!(None, generate(9, 42));
+    }
+
+    #[test]
+    fn day02_tests() {
+        let input = generate(2, 2018).unwrap();
+        let ids = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+        let mut near_pairs = 0;
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                if differences(a, b) < 2 {
+                    near_pairs += 1;
+                }
+            }
+        }
+        assert_eq!(1, near_pairs);
+    }
+}
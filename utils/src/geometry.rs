@@ -0,0 +1,350 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// The y axis points down, like the lines of the puzzle inputs, so turning
+// left takes `RIGHT` to `UP`.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vector2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point2) -> i64 {
+        (other - self).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, other: Point2) -> i64 {
+        (other - self).chebyshev_length()
+    }
+}
+
+impl Vector2 {
+    pub const ZERO: Vector2 = Vector2::new(0, 0);
+    pub const UP: Vector2 = Vector2::new(0, -1);
+    pub const DOWN: Vector2 = Vector2::new(0, 1);
+    pub const LEFT: Vector2 = Vector2::new(-1, 0);
+    pub const RIGHT: Vector2 = Vector2::new(1, 0);
+
+    pub const fn new(x: i64, y: i64) -> Vector2 {
+        Vector2 { x, y }
+    }
+
+    pub fn manhattan_length(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_length(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn turn_left(self) -> Vector2 {
+        Vector2::new(self.y, -self.x)
+    }
+
+    pub fn turn_right(self) -> Vector2 {
+        Vector2::new(-self.y, self.x)
+    }
+
+    /// Rotates the vector by the given number of quarter turns to the right,
+    /// or to the left if `quarter_turns` is negative.
+    pub fn rotate(self, quarter_turns: i32) -> Vector2 {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.turn_right(),
+            2 => -self,
+            _ => self.turn_left(),
+        }
+    }
+}
+
+impl Add<Vector2> for Point2 {
+    type Output = Point2;
+
+    fn add(self, v: Vector2) -> Point2 {
+        Point2::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector2> for Point2 {
+    fn add_assign(&mut self, v: Vector2) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector2> for Point2 {
+    type Output = Point2;
+
+    fn sub(self, v: Vector2) -> Point2 {
+        Point2::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vector2> for Point2 {
+    fn sub_assign(&mut self, v: Vector2) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Point2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Vector2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, k: i64) -> Vector2 {
+        Vector2::new(self.x * k, self.y * k)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2::new(x, y)
+    }
+}
+
+impl From<Point2> for (i64, i64) {
+    fn from(p: Point2) -> (i64, i64) {
+        (p.x, p.y)
+    }
+}
+
+impl From<(i64, i64)> for Vector2 {
+    fn from((x, y): (i64, i64)) -> Vector2 {
+        Vector2::new(x, y)
+    }
+}
+
+impl From<Vector2> for (i64, i64) {
+    fn from(v: Vector2) -> (i64, i64) {
+        (v.x, v.y)
+    }
+}
+
+impl From<Vector2> for Point2 {
+    fn from(v: Vector2) -> Point2 {
+        Point2::new(v.x, v.y)
+    }
+}
+
+impl From<Point2> for Vector2 {
+    fn from(p: Point2) -> Vector2 {
+        Vector2::new(p.x, p.y)
+    }
+}
+
+// Points are printed the way the puzzles expect coordinates in the answers.
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Serialize for Point2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y).serialize(serializer)
+    }
+}
+
+/// An axis-aligned rectangle containing the points from `min` up to, but not
+/// including, `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    pub fn new(left: i64, top: i64, width: i64, height: i64) -> Rect {
+        Rect {
+            min: Point2::new(left, top),
+            max: Point2::new(left + width.max(0), top + height.max(0)),
+        }
+    }
+
+    /// The smallest rectangle containing all the points.
+    pub fn bounding<I>(points: I) -> Option<Rect>
+    where
+        I: IntoIterator<Item = Point2>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (mut min, mut max) = (first, first);
+        for p in points {
+            min = Point2::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point2::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        Some(Rect {
+            min,
+            max: max + Vector2::new(1, 1),
+        })
+    }
+
+    pub fn left(&self) -> i64 {
+        self.min.x
+    }
+
+    pub fn top(&self) -> i64 {
+        self.min.y
+    }
+
+    pub fn right(&self) -> i64 {
+        self.max.x
+    }
+
+    pub fn bottom(&self) -> i64 {
+        self.max.y
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= self.min.x && p.x < self.max.x && p.y >= self.min.y && p.y < self.max.y
+    }
+
+    /// Whether the point lies in the outermost rows or columns of the rectangle.
+    pub fn on_edge(&self, p: Point2) -> bool {
+        self.contains(p)
+            && (p.x == self.min.x
+                || p.x == self.max.x - 1
+                || p.y == self.min.y
+                || p.y == self.max.y - 1)
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect {
+            min: Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        };
+        if rect.min.x < rect.max.x && rect.min.y < rect.max.y {
+            Some(rect)
+        } else {
+            None
+        }
+    }
+
+    /// The smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            min: Point2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// All the points in the rectangle, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (left, right) = (self.min.x, self.max.x);
+        (self.min.y..self.max.y).flat_map(move |y| (left..right).map(move |x| Point2::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_tests() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(Vector2::new(-4, 6), b - a);
+        assert_eq!(b, a + (b - a));
+        assert_eq!(10, a.manhattan_distance(b));
+        assert_eq!(6, a.chebyshev_distance(b));
+        assert_eq!("1,-2", a.to_string());
+        assert_eq!((1, -2), a.into());
+    }
+
+    #[test]
+    fn rotation_tests() {
+        assert_eq!(Vector2::UP, Vector2::RIGHT.turn_left());
+        assert_eq!(Vector2::DOWN, Vector2::RIGHT.turn_right());
+        assert_eq!(Vector2::LEFT, Vector2::UP.turn_left());
+        assert_eq!(Vector2::new(-2, 1), Vector2::new(2, -1).rotate(2));
+        assert_eq!(Vector2::UP.turn_left(), Vector2::UP.rotate(-1));
+        assert_eq!(Vector2::UP.turn_left(), Vector2::UP.rotate(3));
+        assert_eq!(Vector2::UP, Vector2::UP.rotate(8));
+    }
+
+    #[test]
+    fn rect_tests() {
+        let a = Rect::new(1, 3, 4, 4);
+        let b = Rect::new(3, 1, 4, 4);
+        let c = Rect::new(5, 5, 2, 2);
+        assert_eq!(Some(Rect::new(3, 3, 2, 2)), a.intersection(&b));
+        assert!(!a.intersects(&c) && !b.intersects(&c));
+        assert_eq!(Rect::new(1, 1, 6, 6), a.union(&b).union(&c));
+        assert_eq!(16, a.area());
+        assert_eq!(4, c.points().count());
+        assert!(c.on_edge(Point2::new(6, 5)) && !c.on_edge(Point2::new(7, 5)));
+        let points = vec![Point2::new(2, 5), Point2::new(-1, 3)];
+        assert_eq!(Some(Rect::new(-1, 3, 4, 3)), Rect::bounding(points));
+        assert_eq!(None, Rect::bounding(vec![]));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{Error, Point2, Rect, Vector2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub const NEIGHBORS4: [Vector2; 4] = [Vector2::UP, Vector2::RIGHT, Vector2::DOWN, Vector2::LEFT];
+
+pub const NEIGHBORS8: [Vector2; 8] = [
+    Vector2::new(-1, -1),
+    Vector2::new(0, -1),
+    Vector2::new(1, -1),
+    Vector2::new(1, 0),
+    Vector2::new(1, 1),
+    Vector2::new(0, 1),
+    Vector2::new(-1, 1),
+    Vector2::new(-1, 0),
+];
+
+/// A rectangle of cells addressed by points, whose coordinates may be
+/// negative.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    bounds: Rect,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid::with_bounds(Rect::new(0, 0, width as i64, height as i64), fill)
+    }
+
+    pub fn with_bounds(bounds: Rect, fill: T) -> Grid<T> {
+        Grid {
+            bounds,
+            cells: vec![fill; bounds.area() as usize],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F>(bounds: Rect, f: F) -> Grid<T>
+    where
+        F: FnMut(Point2) -> T,
+    {
+        Grid {
+            bounds,
+            cells: bounds.points().map(f).collect(),
+        }
+    }
+
+    /// Parses a grid drawn with one character per cell. Lines shorter than
+    /// the longest one are padded with spaces.
+    pub fn parse<F>(input: &str, mut parse_cell: F) -> Result<Grid<T>, Error>
+    where
+        F: FnMut(Point2, char) -> Result<T, String>,
+    {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.chars().count()).max();
+        let width = width.unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let padded = line.chars().chain(std::iter::repeat(' '));
+            for (x, c) in padded.take(width).enumerate() {
+                let cell = parse_cell(Point2::new(x as i64, y as i64), c)
+                    .map_err(|e| Error::parse(y + 1, x + 1, e))?;
+                cells.push(cell);
+            }
+        }
+        Ok(Grid {
+            bounds: Rect::new(0, 0, width as i64, lines.len() as i64),
+            cells,
+        })
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width() as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height() as usize
+    }
+
+    pub fn left(&self) -> i64 {
+        self.bounds.left()
+    }
+
+    pub fn top(&self) -> i64 {
+        self.bounds.top()
+    }
+
+    /// The first column to the right of the grid.
+    pub fn right(&self) -> i64 {
+        self.bounds.right()
+    }
+
+    /// The first row below the grid.
+    pub fn bottom(&self) -> i64 {
+        self.bounds.bottom()
+    }
+
+    pub fn contains(&self, position: Point2) -> bool {
+        self.bounds.contains(position)
+    }
+
+    fn offset(&self, position: Point2) -> Option<usize> {
+        if self.contains(position) {
+            let from_origin = position - self.bounds.min;
+            Some(from_origin.y as usize * self.width() + from_origin.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, position: Point2) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+        self.offset(position)
+            .map(move |offset| &mut self.cells[offset])
+    }
+
+    /// All the positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point2> {
+        self.bounds.points()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        position: Point2,
+        offsets: &'static [Vector2],
+    ) -> impl Iterator<Item = Point2> + 'a {
+        offsets
+            .iter()
+            .map(move |&offset| position + offset)
+            .filter(move |&neighbor| self.contains(neighbor))
+    }
+
+    /// The orthogonal neighbors of a position that lie in the grid.
+    pub fn neighbors4(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+        self.neighbors(position, &NEIGHBORS4)
+    }
+
+    /// The orthogonal and diagonal neighbors of a position that lie in the grid.
+    pub fn neighbors8(&self, position: Point2) -> impl Iterator<Item = Point2> + '_ {
+        self.neighbors(position, &NEIGHBORS8)
+    }
+
+    pub fn row(&self, y: i64) -> &[T] {
+        assert!(
+            y >= self.top() && y < self.bottom(),
+            "row {} is out of the grid",
+            y
+        );
+        let start = (y - self.top()) as usize * self.width();
+        &self.cells[start..start + self.width()]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (self.top()..self.bottom()).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        assert!(
+            x >= self.left() && x < self.right(),
+            "column {} is out of the grid",
+            x
+        );
+        self.cells
+            .iter()
+            .skip((x - self.left()) as usize)
+            .step_by(self.width().max(1))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            bounds: self.bounds,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point2) -> &T {
+        match self.offset(position) {
+            Some(offset) => &self.cells[offset],
+            None => panic!("{} is out of the grid", position),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, position: Point2) -> &mut T {
+        match self.offset(position) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("{} is out of the grid", position),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_tests() {
+        let mut grid = Grid::with_bounds(Rect::new(-1, -2, 3, 2), 0);
+        assert_eq!(
+            (3, -2, 2, 0),
+            (grid.width(), grid.top(), grid.right(), grid.bottom())
+        );
+        assert!(grid.contains(Point2::new(-1, -2)) && grid.contains(Point2::new(1, -1)));
+        assert!(!grid.contains(Point2::new(2, -1)) && !grid.contains(Point2::new(0, 0)));
+        grid[Point2::new(0, -1)] = 5;
+        assert_eq!(Some(&5), grid.get(Point2::new(0, -1)));
+        assert_eq!(None, grid.get(Point2::new(0, 0)));
+        assert_eq!(&[0, 5, 0], grid.row(-1));
+        assert_eq!(vec![&0, &5], grid.column(0).collect::<Vec<_>>());
+        assert_eq!(
+            vec![Point2::new(0, -2), Point2::new(1, -1), Point2::new(-1, -1)],
+            grid.neighbors4(Point2::new(0, -1)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbors8(Point2::new(0, -2)).count());
+        assert_eq!(
+            vec![(-1, -2), (0, -2), (1, -2), (-1, -1), (0, -1), (1, -1)],
+            grid.positions()
+                .map(|p| p.into())
+                .collect::<Vec<(i64, i64)>>()
+        );
+    }
+
+    #[test]
+    fn parse_tests() {
+        let grid = Grid::parse("#.\n.\n", |_, c| Ok(c)).unwrap();
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert_eq!("#.\n. \n", grid.to_string());
+        let error = Grid::parse("..\n.x", |_, c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(format!("unexpected '{}'", c)),
+        })
+        .unwrap_err();
+        assert_eq!("line 2, column 2: unexpected 'x'", error.to_string());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input of a day comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// The input built into the solution, see `Solution::DEFAULT_INPUT`.
+    Embedded(&'static str),
+}
+
+pub fn input_path(inputs_dir: &Path, day: u32) -> PathBuf {
+    inputs_dir.join(format!("day{:02}.txt", day))
+}
+
+/// Makes the inputs saved on any system read the same: the lines end with
+/// "\n" and have no trailing whitespace, and there are no blank lines at the
+/// end.
+pub fn normalize_input(input: &str) -> String {
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.trim_end().lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized
+}
+
+impl InputSource {
+    /// The source given on the command line, where "-" stands for stdin.
+    pub fn from_arg(arg: &Path) -> InputSource {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_owned())
+        }
+    }
+
+    /// The input saved in the inputs directory, or the embedded one when there
+    /// is no such file.
+    pub fn lookup(
+        inputs_dir: &Path,
+        day: u32,
+        embedded: Option<&'static str>,
+    ) -> Option<InputSource> {
+        let path = input_path(inputs_dir, day);
+        if path.is_file() {
+            Some(InputSource::File(path))
+        } else {
+            embedded.map(InputSource::Embedded)
+        }
+    }
+
+    /// Picks the source for running a single day: the one given on the
+    /// command line, a saved or embedded one, or stdin.
+    pub fn choose(
+        arg: Option<&Path>,
+        inputs_dir: &Path,
+        day: u32,
+        embedded: Option<&'static str>,
+    ) -> InputSource {
+        match arg {
+            Some(arg) => InputSource::from_arg(arg),
+            None => InputSource::lookup(inputs_dir, day, embedded).unwrap_or(InputSource::Stdin),
+        }
+    }
+
+    /// Reads the whole input and normalizes it.
+    pub fn read(&self) -> Result<String, Error> {
+        let input = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| {
+                Error::Io(io::Error::new(
+                    e.kind(),
+                    format!("cannot read {}: {}", path.display(), e),
+                ))
+            })?,
+            InputSource::Embedded(input) => input.to_string(),
+        };
+        Ok(normalize_input(&input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_input_tests() {
+        assert_eq!("+1\n-2\n", normalize_input("+1\r\n-2\r\n"));
+        assert_eq!("+1\n-2\n", normalize_input("+1 \n-2\t\n\n\r\n"));
+        assert_eq!("+1\n-2\n", normalize_input("+1\n-2"));
+        assert_eq!(
+            "  /-\\\n\n  \\-/\n",
+            normalize_input("  /-\\  \r\n\r\n  \\-/")
+        );
+        assert_eq!("", normalize_input("\n \r\n"));
+    }
+
+    #[test]
+    fn source_tests() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input_path(&dir, 3), "#1 @ 1,3: 4x4\r\n").unwrap();
+
+        assert_eq!(
+            InputSource::Stdin,
+            InputSource::choose(Some(Path::new("-")), &dir, 3, None)
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("in.txt")),
+            InputSource::choose(Some(Path::new("in.txt")), &dir, 3, None)
+        );
+        let saved = InputSource::choose(None, &dir, 3, Some("unused"));
+        assert_eq!(InputSource::File(dir.join("day03.txt")), saved);
+        assert_eq!("#1 @ 1,3: 4x4\n", saved.read().unwrap());
+        assert_eq!(
+            InputSource::Embedded("6042"),
+            InputSource::choose(None, &dir, 11, Some("6042"))
+        );
+        assert_eq!(InputSource::Stdin, InputSource::choose(None, &dir, 4, None));
+        assert_eq!(None, InputSource::lookup(&dir, 4, None));
+
+        let missing = InputSource::File(input_path(&dir, 4)).read().unwrap_err();
+        assert!(missing.to_string().starts_with("cannot read "));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
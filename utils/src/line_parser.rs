@@ -0,0 +1,235 @@
+use crate::Error;
+use regex::{Captures, Regex};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses lines matching a regular expression with named groups. The errors
+/// point at the field that failed to parse, as if the line were the whole
+/// input, and `parse_lines` moves them to the right line.
+pub struct LineParser {
+    regex: Regex,
+    expected: &'static str,
+}
+
+/// The groups that matched in a line.
+pub struct Fields<'t> {
+    line: &'t str,
+    caps: Captures<'t>,
+}
+
+// Group names are written with underscores, messages with spaces.
+fn describe(name: &str) -> String {
+    name.replace('_', " ")
+}
+
+impl LineParser {
+    /// `expected` describes the lines, like "a claim like '#1 @ 1,3: 4x4'".
+    pub fn new(pattern: &str, expected: &'static str) -> LineParser {
+        LineParser {
+            regex: Regex::new(pattern).unwrap(),
+            expected,
+        }
+    }
+
+    pub fn fields<'t>(&self, line: &'t str) -> Result<Fields<'t>, Error> {
+        match self.regex.captures(line) {
+            Some(caps) => Ok(Fields { line, caps }),
+            None => Err(Error::parse(1, 1, format!("expected {}", self.expected))),
+        }
+    }
+
+    pub fn parse_line<T, F>(&self, line: &str, mut build: F) -> Result<T, Error>
+    where
+        F: FnMut(&Fields) -> Result<T, Error>,
+    {
+        build(&self.fields(line)?)
+    }
+
+    pub fn parse_lines<T, F>(&self, input: &str, mut build: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(&Fields) -> Result<T, Error>,
+    {
+        parse_lines(input, |line| self.parse_line(line, &mut build))
+    }
+}
+
+impl<'t> Fields<'t> {
+    /// Whether the group took part in the match.
+    pub fn has(&self, name: &str) -> bool {
+        self.caps.name(name).is_some()
+    }
+
+    pub fn str(&self, name: &str) -> Result<&'t str, Error> {
+        match self.caps.name(name) {
+            Some(field) => Ok(field.as_str()),
+            None => Err(Error::parse(1, 1, format!("missing {}", describe(name)))),
+        }
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get_with(name, str::parse)
+    }
+
+    /// Like `get`, but converts the text of the field with `convert`.
+    pub fn get_with<T, E, F>(&self, name: &str, convert: F) -> Result<T, Error>
+    where
+        E: Display,
+        F: FnOnce(&'t str) -> Result<T, E>,
+    {
+        let field = self
+            .caps
+            .name(name)
+            .ok_or_else(|| Error::parse(1, 1, format!("missing {}", describe(name))))?;
+        convert(field.as_str()).map_err(|e| {
+            let message = format!("invalid {}: {}", describe(name), e);
+            let column = self.line[..field.start()].chars().count() + 1;
+            Error::parse(1, column, message)
+        })
+    }
+}
+
+/// Parses every line with `parse_line`, numbering the lines in its errors.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, Error>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Defines a function parsing one line with a pattern, whose named groups are
+/// parsed into the typed parameters of the closure that builds the result:
+///
+/// ```
+/// utils::line_parser! {
+///     fn parse_move -> (char, u32) {
+///         pattern: r"(?P<direction>[UDLR]) (?P<steps>\d+)",
+///         expected: "a move like 'U 5'",
+///         |direction: char, steps: u32| (direction, steps)
+///     }
+/// }
+///
+/// assert_eq!(('L', 12), parse_move("L 12").unwrap());
+/// let error = utils::parse_lines("U 1\nD x", parse_move).unwrap_err();
+/// assert_eq!("line 2, column 1: expected a move like 'U 5'", error.to_string());
+/// ```
+///
+/// The closure may use `?` on `utils::Error`s. The pattern is compiled once.
+#[macro_export]
+macro_rules! line_parser {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident -> $output:ty {
+            pattern: $pattern:expr,
+            expected: $expected:expr,
+            |$($field:ident: $field_type:ty),* $(,)?| $build:expr $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis fn $name(line: &str) -> ::std::result::Result<$output, $crate::Error> {
+            static PARSER: ::std::sync::OnceLock<$crate::LineParser> =
+                ::std::sync::OnceLock::new();
+            let parser = PARSER.get_or_init(|| $crate::LineParser::new($pattern, $expected));
+            parser.parse_line(line, |fields| {
+                $(let $field: $field_type = fields.get(stringify!($field))?;)*
+                Ok($build)
+            })
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Claim {
+        id: u32,
+        left: i64,
+        top: i64,
+    }
+
+    line_parser! {
+        fn parse_claim -> Claim {
+            pattern: r"#(?P<id>\d+) @ (?P<left>-?\d+),(?P<top>-?\d+)",
+            expected: "a claim like '#1 @ 1,3'",
+            |id: u32, left: i64, top: i64| Claim { id, left, top }
+        }
+    }
+
+    #[test]
+    fn line_parser_tests() {
+        let claims = parse_lines("#1 @ 1,3\n#2 @ -4,0\n", parse_claim).unwrap();
+        assert_eq!(
+            vec![
+                Claim {
+                    id: 1,
+                    left: 1,
+                    top: 3
+                },
+                Claim {
+                    id: 2,
+                    left: -4,
+                    top: 0
+                },
+            ],
+            claims
+        );
+
+        let error = parse_lines("#1 @ 1,3\n#2 @ 4\n", parse_claim).unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a claim like '#1 @ 1,3'",
+            error.to_string()
+        );
+        let error = parse_lines("#99999999999 @ 1,3", parse_claim).unwrap_err();
+        assert_eq!(
+            "line 1, column 2: invalid id: number too large to fit in target type",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn fields_tests() {
+        let parser = LineParser::new(
+            r"(?P<guard_id>\d+) (?:(?P<sleeps>sleeps)|(?P<wakes>wakes))",
+            "an event like '10 sleeps'",
+        );
+        let fields = parser.fields("at 10 wakes").unwrap();
+        assert!(fields.has("wakes") && !fields.has("sleeps"));
+        assert_eq!("10", fields.str("guard_id").unwrap());
+        assert_eq!(
+            "line 1, column 1: missing sleeps",
+            fields.get::<String>("sleeps").unwrap_err().to_string()
+        );
+        let error = fields
+            .get_with(
+                "guard_id",
+                |id| if id == "10" { Err("retired") } else { Ok(id) },
+            )
+            .unwrap_err();
+        assert_eq!(
+            "line 1, column 4: invalid guard id: retired",
+            error.to_string()
+        );
+
+        let fields = parser.fields("čas 1000 wakes").unwrap();
+        assert_eq!(
+            "line 1, column 5: invalid guard id: number too large to fit in target type",
+            fields
+                .get::<u8>("guard_id")
+                .map(|_| ())
+                .unwrap_err()
+                .to_string()
+        );
+
+        let ids = parser.parse_lines("1 sleeps\n2 wakes", |fields| fields.get::<u32>("guard_id"));
+        assert_eq!(vec![1, 2], ids.unwrap());
+    }
+}
@@ -0,0 +1,441 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::iter::FusedIterator;
+
+/// Pairs every item of `outer` with every item of the iterator that
+/// `inner_generator` makes for it.
+pub fn iter_dep_product<Outer, Inner, F>(
+    outer: Outer,
+    inner_generator: F,
+) -> IterDepProduct<Outer, Inner, F>
+where
+    Outer: Iterator,
+    Outer::Item: Clone,
+    Inner: Iterator,
+    F: FnMut(Outer::Item) -> Inner,
+{
+    IterDepProduct {
+        state: State::new(outer),
+        inner_generator,
+    }
+}
+
+/// Pairs every item of `outer` with every item of `inner`.
+pub fn iter_product<Outer, Inner>(outer: Outer, inner: Inner) -> IterProduct<Outer, Inner>
+where
+    Outer: Iterator,
+    Outer::Item: Clone,
+    Inner: Iterator + Clone,
+{
+    IterProduct {
+        state: State::new(outer),
+        inner,
+    }
+}
+
+/// The parallel version of `iter_dep_product`, which yields the same pairs,
+/// though not in order.
+#[cfg(feature = "parallel")]
+pub fn par_iter_dep_product<Outer, Inner, F>(
+    outer: Outer,
+    inner_generator: F,
+) -> impl ParallelIterator<Item = (Outer::Item, Inner::Item)>
+where
+    Outer: IntoParallelIterator,
+    Outer::Item: Clone + Sync,
+    Inner: IntoParallelIterator,
+    F: Fn(Outer::Item) -> Inner + Sync + Send,
+{
+    outer.into_par_iter().flat_map(move |o| {
+        inner_generator(o.clone())
+            .into_par_iter()
+            .map(move |i| (o.clone(), i))
+    })
+}
+
+/// The parallel version of `iter_product`.
+#[cfg(feature = "parallel")]
+pub fn par_iter_product<Outer, Inner>(
+    outer: Outer,
+    inner: Inner,
+) -> impl ParallelIterator<Item = (Outer::Item, Inner::Item)>
+where
+    Outer: IntoParallelIterator,
+    Outer::Item: Clone + Sync,
+    Inner: IntoParallelIterator + Clone + Sync + Send,
+{
+    par_iter_dep_product(outer, move |_| inner.clone())
+}
+
+/// The product of any number of iterators as flat tuples, e.g.
+/// `product!(0..2, 0..3, 0..4)` yields the 24 triples from `(0, 0, 0)` to
+/// `(1, 2, 3)`. All but the last iterator must be `Clone`, as must the items
+/// of all but the last one.
+#[macro_export]
+macro_rules! product {
+    ($a:expr $(,)?) => {
+        ::std::iter::IntoIterator::into_iter($a).map(|a| (a,))
+    };
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::iter_product(
+            ::std::iter::IntoIterator::into_iter($a),
+            ::std::iter::IntoIterator::into_iter($b),
+        )
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::iter_product(
+            ::std::iter::IntoIterator::into_iter($a),
+            $crate::product!($b, $c),
+        )
+        .map(|(a, (b, c))| (a, b, c))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::iter_product(
+            ::std::iter::IntoIterator::into_iter($a),
+            $crate::product!($b, $c, $d),
+        )
+        .map(|(a, (b, c, d))| (a, b, c, d))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr $(,)?) => {
+        $crate::iter_product(
+            ::std::iter::IntoIterator::into_iter($a),
+            $crate::product!($b, $c, $d, $e),
+        )
+        .map(|(a, (b, c, d, e))| (a, b, c, d, e))
+    };
+}
+
+// The outer iterator with the inner iterators started from both of its ends,
+// the way `Iterator::flatten` works.
+struct State<Outer: Iterator, Inner> {
+    outer: Outer,
+    front: Option<(Outer::Item, Inner)>,
+    back: Option<(Outer::Item, Inner)>,
+}
+
+impl<Outer, Inner> Clone for State<Outer, Inner>
+where
+    Outer: Iterator + Clone,
+    Outer::Item: Clone,
+    Inner: Clone,
+{
+    fn clone(&self) -> Self {
+        State {
+            outer: self.outer.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<Outer, Inner> State<Outer, Inner>
+where
+    Outer: Iterator,
+    Outer::Item: Clone,
+    Inner: Iterator,
+{
+    fn new(outer: Outer) -> Self {
+        State {
+            outer,
+            front: None,
+            back: None,
+        }
+    }
+
+    fn next<F>(&mut self, mut generate: F) -> Option<(Outer::Item, Inner::Item)>
+    where
+        F: FnMut(Outer::Item) -> Inner,
+    {
+        loop {
+            if let Some((o, inner)) = &mut self.front {
+                if let Some(i) = inner.next() {
+                    return Some((o.clone(), i));
+                }
+                self.front = None;
+            }
+            match self.outer.next() {
+                Some(o) => self.front = Some((o.clone(), generate(o))),
+                None => {
+                    let (o, inner) = self.back.as_mut()?;
+                    let item = inner.next().map(|i| (o.clone(), i));
+                    if item.is_none() {
+                        self.back = None;
+                    }
+                    return item;
+                }
+            }
+        }
+    }
+
+    fn next_back<F>(&mut self, mut generate: F) -> Option<(Outer::Item, Inner::Item)>
+    where
+        Outer: DoubleEndedIterator,
+        Inner: DoubleEndedIterator,
+        F: FnMut(Outer::Item) -> Inner,
+    {
+        loop {
+            if let Some((o, inner)) = &mut self.back {
+                if let Some(i) = inner.next_back() {
+                    return Some((o.clone(), i));
+                }
+                self.back = None;
+            }
+            match self.outer.next_back() {
+                Some(o) => self.back = Some((o.clone(), generate(o))),
+                None => {
+                    let (o, inner) = self.front.as_mut()?;
+                    let item = inner.next_back().map(|i| (o.clone(), i));
+                    if item.is_none() {
+                        self.front = None;
+                    }
+                    return item;
+                }
+            }
+        }
+    }
+
+    // `per_outer` bounds the length of the inner iterators still to be made.
+    fn size_hint(&self, per_outer: (usize, Option<usize>)) -> (usize, Option<usize>) {
+        let started = |side: &Option<(Outer::Item, Inner)>| match side {
+            Some((_, inner)) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        let (front_low, front_high) = started(&self.front);
+        let (back_low, back_high) = started(&self.back);
+        let (outer_low, outer_high) = self.outer.size_hint();
+
+        let low = front_low
+            .saturating_add(back_low)
+            .saturating_add(outer_low.saturating_mul(per_outer.0));
+        let rest_high = match (outer_high, per_outer.1) {
+            (Some(0), _) | (_, Some(0)) => Some(0),
+            (Some(a), Some(b)) => a.checked_mul(b),
+            _ => None,
+        };
+        let high = match (front_high, back_high, rest_high) {
+            (Some(a), Some(b), Some(c)) => a.checked_add(b).and_then(|ab| ab.checked_add(c)),
+            _ => None,
+        };
+        (low, high)
+    }
+}
+
+pub struct IterDepProduct<Outer: Iterator, Inner, F> {
+    state: State<Outer, Inner>,
+    inner_generator: F,
+}
+
+impl<Outer, Inner, F> Clone for IterDepProduct<Outer, Inner, F>
+where
+    Outer: Iterator + Clone,
+    Outer::Item: Clone,
+    Inner: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        IterDepProduct {
+            state: self.state.clone(),
+            inner_generator: self.inner_generator.clone(),
+        }
+    }
+}
+
+impl<Outer, Inner, F> Iterator for IterDepProduct<Outer, Inner, F>
+where
+    Outer: Iterator,
+    Outer::Item: Clone,
+    Inner: Iterator,
+    F: FnMut(Outer::Item) -> Inner,
+{
+    type Item = (Outer::Item, Inner::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(&mut self.inner_generator)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Nothing is known about the inner iterators not made yet.
+        self.state.size_hint((0, None))
+    }
+}
+
+impl<Outer, Inner, F> DoubleEndedIterator for IterDepProduct<Outer, Inner, F>
+where
+    Outer: DoubleEndedIterator,
+    Outer::Item: Clone,
+    Inner: DoubleEndedIterator,
+    F: FnMut(Outer::Item) -> Inner,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.state.next_back(&mut self.inner_generator)
+    }
+}
+
+impl<Outer, Inner, F> FusedIterator for IterDepProduct<Outer, Inner, F>
+where
+    Outer: FusedIterator,
+    Outer::Item: Clone,
+    Inner: Iterator,
+    F: FnMut(Outer::Item) -> Inner,
+{
+}
+
+pub struct IterProduct<Outer: Iterator, Inner> {
+    state: State<Outer, Inner>,
+    inner: Inner,
+}
+
+impl<Outer, Inner> Clone for IterProduct<Outer, Inner>
+where
+    Outer: Iterator + Clone,
+    Outer::Item: Clone,
+    Inner: Clone,
+{
+    fn clone(&self) -> Self {
+        IterProduct {
+            state: self.state.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Outer, Inner> Iterator for IterProduct<Outer, Inner>
+where
+    Outer: Iterator,
+    Outer::Item: Clone,
+    Inner: Iterator + Clone,
+{
+    type Item = (Outer::Item, Inner::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = &self.inner;
+        self.state.next(|_| inner.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint(self.inner.size_hint())
+    }
+}
+
+impl<Outer, Inner> DoubleEndedIterator for IterProduct<Outer, Inner>
+where
+    Outer: DoubleEndedIterator,
+    Outer::Item: Clone,
+    Inner: DoubleEndedIterator + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = &self.inner;
+        self.state.next_back(|_| inner.clone())
+    }
+}
+
+impl<Outer, Inner> FusedIterator for IterProduct<Outer, Inner>
+where
+    Outer: FusedIterator,
+    Outer::Item: Clone,
+    Inner: Iterator + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter_product_tests() {
+        let pairs = iter_product(0..2, vec!['a', 'b'].into_iter());
+        assert_eq!((4, Some(4)), pairs.size_hint());
+        assert_eq!(
+            vec![(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')],
+            pairs.clone().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 'b'), (1, 'a'), (0, 'b'), (0, 'a')],
+            pairs.rev().collect::<Vec<_>>()
+        );
+
+        let mut pairs = iter_product(0..3, 0..3);
+        assert_eq!(Some((0, 0)), pairs.next());
+        assert_eq!(Some((2, 2)), pairs.next_back());
+        assert_eq!((7, Some(7)), pairs.size_hint());
+        let rest = pairs.clone().collect::<Vec<_>>();
+        assert_eq!(rest.len(), 7);
+        assert_eq!((Some(&(0, 1)), Some(&(2, 1))), (rest.first(), rest.last()));
+        assert_eq!(Some((2, 1)), pairs.next_back());
+        assert_eq!((6, Some(6)), pairs.size_hint());
+
+        assert_eq!(0, iter_product(0..3, 0..0).count());
+        assert_eq!((0, Some(0)), iter_product(0..3, 0..0).size_hint());
+        assert_eq!((usize::MAX, None), iter_product(0.., 0..2).size_hint());
+
+        // Outer items only need to be `Clone`.
+        let names = vec!["x".to_string(), "y".to_string()];
+        let labels = iter_product(names.into_iter(), 1..=2)
+            .map(|(name, i)| format!("{}{}", name, i))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["x1", "x2", "y1", "y2"], labels);
+    }
+
+    #[test]
+    fn iter_dep_product_tests() {
+        let triangle = iter_dep_product(0..4, |i| 0..i);
+        assert_eq!((0, None), triangle.size_hint());
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (3, 0), (3, 1), (3, 2)],
+            triangle.clone().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(3, 2), (3, 1), (3, 0), (2, 1), (2, 0), (1, 0)],
+            triangle.rev().collect::<Vec<_>>()
+        );
+
+        let mut triangle = iter_dep_product(0..4, |i| 0..i);
+        assert_eq!(Some((1, 0)), triangle.next());
+        assert_eq!(Some((3, 2)), triangle.next_back());
+        let middle = triangle.by_ref().take(3).collect::<Vec<_>>();
+        assert_eq!(vec![(2, 0), (2, 1), (3, 0)], middle);
+        assert_eq!((1, Some(1)), triangle.size_hint());
+        assert_eq!(Some((3, 1)), triangle.next());
+        assert_eq!(None, triangle.next());
+        assert_eq!(None, triangle.next_back());
+    }
+
+    #[test]
+    fn empty_inner_tests() {
+        // Long runs of empty inner iterators must not grow the stack.
+        let sparse = iter_dep_product(0..1_000_000, |i| 0..(i % 500_000 == 499_999) as u32);
+        assert_eq!(vec![(499_999, 0), (999_999, 0)], sparse.collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_iter_product_tests() {
+        let mut pairs = par_iter_product(0..20, 0..30).collect::<Vec<_>>();
+        pairs.sort();
+        assert_eq!(iter_product(0..20, 0..30).collect::<Vec<_>>(), pairs);
+
+        let mut triangle = par_iter_dep_product(0..50, |i| 0..i).collect::<Vec<_>>();
+        triangle.sort();
+        assert_eq!(
+            iter_dep_product(0..50, |i| 0..i).collect::<Vec<_>>(),
+            triangle
+        );
+    }
+
+    #[test]
+    fn product_macro_tests() {
+        assert_eq!(vec![(1,), (2,)], product!(1..3).collect::<Vec<_>>());
+        let triples = product!(0..2, 0..3, vec!['a', 'b']);
+        assert_eq!((12, Some(12)), triples.size_hint());
+        assert_eq!(
+            vec![(0, 0, 'a'), (0, 0, 'b'), (0, 1, 'a')],
+            triples.clone().take(3).collect::<Vec<_>>()
+        );
+        assert_eq!(Some((1, 2, 'b')), triples.clone().next_back());
+        assert_eq!(2 * 3 * 4 * 5, product!(0..2, 0..3, 0..4, 0..5).count());
+        assert_eq!(
+            Some((1, 2, 3, 4, 5)),
+            product!(0..2, 0..3, 0..4, 0..5, 0..6).last()
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The number of steps from `start` to every node reachable from it.
+pub fn bfs<N, F, I>(start: N, mut neighbors: F) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    distances.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for neighbor in neighbors(&node) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), distance + 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Counts the nodes of the region around `start`. `include` decides whether a
+/// node belongs to the region, or returns `None` to abort the whole search,
+/// e.g. once the region turns out to be infinite.
+pub fn flood_fill<N, P, F, I>(start: N, mut include: P, mut neighbors: F) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    P: FnMut(&N) -> Option<bool>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut scheduled = HashSet::new();
+    let mut stack = Vec::new();
+    let mut size = 0;
+
+    scheduled.insert(start.clone());
+    stack.push(start);
+
+    while let Some(node) = stack.pop() {
+        if include(&node)? {
+            size += 1;
+            for neighbor in neighbors(&node) {
+                if scheduled.insert(neighbor.clone()) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    Some(size)
+}
+
+// A node waiting in the open set, ordered so that the cheapest estimate is on
+// the top of the max-heap.
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates, prefer the nodes further along the path.
+        (Reverse(&self.estimate), &self.cost).cmp(&(Reverse(&other.estimate), &other.cost))
+    }
+}
+
+/// The cheapest path from `start` to a node satisfying `is_goal` and its cost,
+/// with `neighbors` listing the nodes reachable in one step and their costs.
+pub fn dijkstra<N, C, F, I, G>(start: N, neighbors: F, is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but guided by a `heuristic` which must never overestimate
+/// the remaining cost to a goal. A node is expanded again whenever a cheaper
+/// path to it turns up, so the heuristic need not be consistent.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut open = BinaryHeap::new();
+    best.insert(start.clone(), (C::default(), None));
+    open.push(Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = open.pop() {
+        // Skip the candidates superseded by a cheaper path to the same node.
+        if cost > best[&node].0 {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&best, node), cost));
+        }
+        for (neighbor, step_cost) in neighbors(&node) {
+            let neighbor_cost = cost + step_cost;
+            let improved = match best.get(&neighbor) {
+                Some(&(known, _)) => neighbor_cost < known,
+                None => true,
+            };
+            if improved {
+                best.insert(neighbor.clone(), (neighbor_cost, Some(node.clone())));
+                open.push(Candidate {
+                    estimate: neighbor_cost + heuristic(&neighbor),
+                    cost: neighbor_cost,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct_path<N, C>(best: &HashMap<N, (C, Option<N>)>, goal: N) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut path = vec![goal];
+    while let Some((_, Some(parent))) = best.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Orders the nodes so that every node comes after all its predecessors,
+/// picking the smallest of the available nodes first. The nodes that are part
+/// of a cycle, or depend on one, are left out.
+pub fn topological_sort<N, F, I>(nodes: &[N], mut successors: F) -> Vec<N>
+where
+    N: Ord + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut remaining_predecessors: HashMap<N, usize> =
+        nodes.iter().map(|node| (node.clone(), 0)).collect();
+    for node in nodes {
+        for successor in successors(node) {
+            *remaining_predecessors.entry(successor).or_insert(0) += 1;
+        }
+    }
+
+    let mut available = remaining_predecessors
+        .iter()
+        .filter(|&(_, &count)| count == 0)
+        .map(|(node, _)| Reverse(node.clone()))
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::new();
+    while let Some(Reverse(node)) = available.pop() {
+        for successor in successors(&node) {
+            let count = remaining_predecessors.get_mut(&successor).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                available.push(Reverse(successor));
+            }
+        }
+        order.push(node);
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point2};
+
+    const MAZE: &str = "\
+#########
+#S..#...#
+#.#.#.#.#
+#.#...#G#
+#########
+";
+
+    fn maze() -> (Grid<char>, Point2, Point2) {
+        let grid = Grid::parse(MAZE, |_, c| Ok(c)).unwrap();
+        let find = |wanted| grid.iter().find(|&(_, &c)| c == wanted).unwrap().0;
+        let (start, goal) = (find('S'), find('G'));
+        (grid, start, goal)
+    }
+
+    fn open_neighbors(grid: &Grid<char>, p: Point2) -> Vec<Point2> {
+        grid.neighbors4(p).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_tests() {
+        let (grid, start, goal) = maze();
+        let distances = bfs(start, |&p| open_neighbors(&grid, p));
+        assert_eq!(Some(&12), distances.get(&goal));
+        assert_eq!(Some(&4), distances.get(&Point2::new(3, 3)));
+        assert_eq!(15, distances.len());
+    }
+
+    #[test]
+    fn flood_fill_tests() {
+        let (grid, start, _) = maze();
+        let open = |p: &Point2| Some(grid[*p] != '#');
+        assert_eq!(Some(15), flood_fill(start, open, |&p| grid.neighbors4(p)));
+        let abort_at_goal = |p: &Point2| if grid[*p] == 'G' { None } else { open(p) };
+        assert_eq!(
+            None,
+            flood_fill(start, abort_at_goal, |&p| grid.neighbors4(p))
+        );
+    }
+
+    #[test]
+    fn shortest_path_tests() {
+        let (grid, start, goal) = maze();
+        let neighbors = |p: &Point2| open_neighbors(&grid, *p).into_iter().map(|n| (n, 1));
+        let (path, cost) = dijkstra(start, neighbors, |&p| p == goal).unwrap();
+        assert_eq!(12, cost);
+        assert_eq!(13, path.len());
+        assert_eq!((Some(&start), Some(&goal)), (path.first(), path.last()));
+        assert!(path.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1));
+
+        let heuristic = |p: &Point2| p.manhattan_distance(goal);
+        let (astar_path, astar_cost) = astar(start, neighbors, heuristic, |&p| p == goal).unwrap();
+        assert_eq!((path.len(), cost), (astar_path.len(), astar_cost));
+        assert_eq!(None, dijkstra(start, neighbors, |&p| p == Point2::ORIGIN));
+
+        // The direct edge is dearer than the detour.
+        let edges = |&n: &char| match n {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        };
+        assert_eq!(
+            Some((vec!['a', 'b', 'c'], 3)),
+            dijkstra('a', edges, |&n| n == 'c')
+        );
+
+        // The heuristic is admissible but not consistent, so `c` is first
+        // reached the dear way, through `a`, and must be expanded again once
+        // the cheaper way through `b` turns up.
+        let edges = |&n: &char| match n {
+            's' => vec![('a', 1), ('b', 2)],
+            'a' => vec![('c', 3)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('g', 3)],
+            _ => vec![],
+        };
+        let heuristic = |&n: &char| if n == 'b' { 3 } else { 0 };
+        assert_eq!(
+            Some((vec!['s', 'b', 'c', 'g'], 6)),
+            astar('s', edges, heuristic, |&n| n == 'g')
+        );
+    }
+
+    #[test]
+    fn topological_sort_tests() {
+        let successors = |&n: &char| match n {
+            'C' => vec!['A', 'F'],
+            'A' => vec!['B', 'D'],
+            'B' | 'D' | 'F' => vec!['E'],
+            _ => vec![],
+        };
+        let nodes = ['A', 'B', 'C', 'D', 'E', 'F'];
+        assert_eq!(
+            vec!['C', 'A', 'B', 'D', 'F', 'E'],
+            topological_sort(&nodes, successors)
+        );
+        let cycle = |&n: &u8| vec![(n + 1) % 3];
+        assert!(topological_sort(&[0, 1, 2], cycle).is_empty());
+    }
+}
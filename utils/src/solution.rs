@@ -0,0 +1,88 @@
+use crate::{Error, InputSource};
+use serde::Serialize;
+use std::env;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process;
+
+pub trait Solution {
+    type Input;
+    type Answer1: Display + Serialize;
+    type Answer2: Display + Serialize;
+
+    /// The puzzle input to use when none is given, for the days whose input
+    /// is short enough to be built into the solution.
+    const DEFAULT_INPUT: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1, Error>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2, Error>;
+}
+
+fn print_answer<A: Display>(part: u32, answer: A) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer.trim_end());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+fn input_arg<I: IntoIterator<Item = OsString>>(args: I) -> Result<Option<PathBuf>, Error> {
+    let usage = || {
+        Error::Io(io::Error::new(
+            ErrorKind::InvalidInput,
+            "usage: [--input <path>]",
+        ))
+    };
+    let mut args = args.into_iter();
+    match (args.next(), args.next(), args.next()) {
+        (None, _, _) => Ok(None),
+        (Some(flag), Some(path), None) if flag == "--input" => Ok(Some(PathBuf::from(path))),
+        _ => Err(usage()),
+    }
+}
+
+/// The source `run` reads the puzzle input of the day from, given the
+/// command line arguments other than the program name.
+pub fn input_source<S, I>(day: u32, args: I) -> Result<InputSource, Error>
+where
+    S: Solution,
+    I: IntoIterator<Item = OsString>,
+{
+    let arg = input_arg(args)?;
+    Ok(InputSource::choose(
+        arg.as_deref(),
+        Path::new("inputs"),
+        day,
+        S::DEFAULT_INPUT,
+    ))
+}
+
+/// Reads the puzzle input of the day like `run` does, for binaries that print
+/// more than the answers.
+pub fn read_input<S: Solution>(day: u32) -> Result<String, Error> {
+    input_source::<S, _>(day, env::args_os().skip(1))?.read()
+}
+
+fn try_run<S: Solution>(day: u32, solution: S) -> Result<(), Error> {
+    let input = S::parse(&read_input::<S>(day)?)?;
+    print_answer(1, solution.part1(&input)?);
+    print_answer(2, solution.part2(&input)?);
+    Ok(())
+}
+
+/// Solves the puzzle of the day, exiting with an error message on failure. The
+/// input is read from the file given with `--input <path>` ("-" for stdin),
+/// from inputs/dayNN.txt, from the one built into the solution, or else from
+/// stdin.
+pub fn run<S: Solution>(day: u32, solution: S) {
+    if let Err(e) = try_run(day, solution) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}